@@ -0,0 +1,278 @@
+//! Stable message keys and replaceable rendering for builder errors.
+//!
+//! Builders that adopt [`MessageKey`] still produce an [`Error`] whose `message` is the default
+//! English text, so existing callers see no difference. The key and the values substituted into
+//! the text are also recorded on the error, readable through [`Error::message_key`] and
+//! [`Error::message_args`], which lets a consumer re-render the error through its own
+//! [`Messages`] implementation, for example to translate it. They are kept out of
+//! [`Error::context`] and out of equality, so a keyed error still equals a plain error with the
+//! same audience, kind, and message.
+
+#[cfg(test)]
+mod tests;
+
+use crate::error::{Audience, Error, Kind};
+use std::hash::{Hash, Hasher};
+
+/// The [`MessageKey`] and arguments an [`Error`] message was rendered from.
+///
+/// Every origin compares equal and hashes to nothing, so it never affects [`Error`] equality.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MessageOrigin {
+    pub(crate) key: Option<MessageKey>,
+    pub(crate) args: Vec<(String, String)>,
+}
+
+impl PartialEq for MessageOrigin {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageOrigin {}
+
+impl Hash for MessageOrigin {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Identifies a builder error message independently of its rendered text.
+///
+/// Each key has a stable string form, returned by [`MessageKey::as_str`], and a default English
+/// template in which `{name}` placeholders are filled from the error's
+/// [`Error::message_args`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MessageKey {
+    /// A file name was empty or only whitespace.
+    FileNameEmpty,
+    /// A file name was `.` or `..`.
+    FileNameDotSegment,
+    /// A file name started with a disallowed character.
+    FileNameInvalidStart,
+    /// A file name contained a disallowed character.
+    FileNameInvalidCharacter,
+    /// A repository link builder had no allowed schemes.
+    RepositoryLinkNoAllowedSchemes,
+    /// A repository link builder had no default scheme.
+    RepositoryLinkDefaultSchemeMissing,
+    /// A repository link default scheme was empty or only whitespace.
+    RepositoryLinkDefaultSchemeBlank,
+    /// A repository link default scheme was not one of the allowed schemes.
+    RepositoryLinkDefaultSchemeNotAllowed,
+    /// A repository link was not provided.
+    RepositoryLinkMissing,
+    /// A repository link was empty or only whitespace.
+    RepositoryLinkBlank,
+    /// A repository link had neither a leading host nor a `scheme://` separator.
+    RepositoryLinkMissingSchemeSeparator,
+    /// A repository link used a scheme that is not allowed.
+    RepositoryLinkSchemeNotAllowed,
+    /// A repository link could not be parsed as a URL.
+    RepositoryLinkMalformed,
+    /// A repository link had no host and was not a `file://` link.
+    RepositoryLinkMissingHost,
+    /// A repository link host contained whitespace or percent-encoded characters.
+    RepositoryLinkInvalidHost,
+    /// A named field had an invalid value.
+    FieldInvalid,
+    /// A named field was larger than its maximum.
+    FieldExceedsMax,
+    /// An entity with the given id was not found.
+    EntityNotFound,
+}
+
+impl MessageKey {
+    /// Every message key, in declaration order.
+    pub const ALL: [MessageKey; 18] = [
+        MessageKey::FileNameEmpty,
+        MessageKey::FileNameDotSegment,
+        MessageKey::FileNameInvalidStart,
+        MessageKey::FileNameInvalidCharacter,
+        MessageKey::RepositoryLinkNoAllowedSchemes,
+        MessageKey::RepositoryLinkDefaultSchemeMissing,
+        MessageKey::RepositoryLinkDefaultSchemeBlank,
+        MessageKey::RepositoryLinkDefaultSchemeNotAllowed,
+        MessageKey::RepositoryLinkMissing,
+        MessageKey::RepositoryLinkBlank,
+        MessageKey::RepositoryLinkMissingSchemeSeparator,
+        MessageKey::RepositoryLinkSchemeNotAllowed,
+        MessageKey::RepositoryLinkMalformed,
+        MessageKey::RepositoryLinkMissingHost,
+        MessageKey::RepositoryLinkInvalidHost,
+        MessageKey::FieldInvalid,
+        MessageKey::FieldExceedsMax,
+        MessageKey::EntityNotFound,
+    ];
+
+    /// Returns the stable string form of the key, such as `file_name.empty`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageKey::FileNameEmpty => "file_name.empty",
+            MessageKey::FileNameDotSegment => "file_name.dot_segment",
+            MessageKey::FileNameInvalidStart => "file_name.invalid_start",
+            MessageKey::FileNameInvalidCharacter => "file_name.invalid_character",
+            MessageKey::RepositoryLinkNoAllowedSchemes => "repository_link.no_allowed_schemes",
+            MessageKey::RepositoryLinkDefaultSchemeMissing => {
+                "repository_link.default_scheme_missing"
+            }
+            MessageKey::RepositoryLinkDefaultSchemeBlank => "repository_link.default_scheme_blank",
+            MessageKey::RepositoryLinkDefaultSchemeNotAllowed => {
+                "repository_link.default_scheme_not_allowed"
+            }
+            MessageKey::RepositoryLinkMissing => "repository_link.missing",
+            MessageKey::RepositoryLinkBlank => "repository_link.blank",
+            MessageKey::RepositoryLinkMissingSchemeSeparator => {
+                "repository_link.missing_scheme_separator"
+            }
+            MessageKey::RepositoryLinkSchemeNotAllowed => "repository_link.scheme_not_allowed",
+            MessageKey::RepositoryLinkMalformed => "repository_link.malformed",
+            MessageKey::RepositoryLinkMissingHost => "repository_link.missing_host",
+            MessageKey::RepositoryLinkInvalidHost => "repository_link.invalid_host",
+            MessageKey::FieldInvalid => "field.invalid",
+            MessageKey::FieldExceedsMax => "field.exceeds_max",
+            MessageKey::EntityNotFound => "entity.not_found",
+        }
+    }
+
+    /// Returns the key whose [`MessageKey::as_str`] form is `key`, if any.
+    pub fn from_key_str(key: &str) -> Option<MessageKey> {
+        MessageKey::ALL
+            .into_iter()
+            .find(|message_key| message_key.as_str() == key)
+    }
+
+    /// Returns the default English template, with `{name}` placeholders for substituted values.
+    pub fn default_template(&self) -> &'static str {
+        match self {
+            MessageKey::FileNameEmpty => "The file name cannot be empty.",
+            MessageKey::FileNameDotSegment => "The file name cannot be '.' or '..'.",
+            MessageKey::FileNameInvalidStart => {
+                "The file name must start with a letter, number, '-', '.', or '_'."
+            }
+            MessageKey::FileNameInvalidCharacter => {
+                "The file name can only contain alphanumeric characters, '.', '_', or '-'."
+            }
+            MessageKey::RepositoryLinkNoAllowedSchemes => {
+                "No allowed schemes were provided, please provide at least one allowed scheme."
+            }
+            MessageKey::RepositoryLinkDefaultSchemeMissing => {
+                "A default scheme was not provided. Please provide a default scheme."
+            }
+            MessageKey::RepositoryLinkDefaultSchemeBlank => {
+                "The provided default scheme is empty or all whitespace. Please provide a non-empty default scheme."
+            }
+            MessageKey::RepositoryLinkDefaultSchemeNotAllowed => {
+                "The provided default scheme '{default_scheme}' is not in the list of allowed schemes: {allowed_schemes}. Either provide a default scheme that is in the list of allowed schemes, or update the list of allowed schema to include the default schema."
+            }
+            MessageKey::RepositoryLinkMissing => {
+                "A repository link was not provided. Please provide a repository link."
+            }
+            MessageKey::RepositoryLinkBlank => {
+                "The provided repository link is empty or all whitespace. Please provide a non-empty repository link."
+            }
+            MessageKey::RepositoryLinkMissingSchemeSeparator => {
+                "The repository link [{repo_link}] is malformed. It must either start with a scheme separator '://' or be formatted as [scheme]://[host] per for the RFC 3986 specification."
+            }
+            MessageKey::RepositoryLinkSchemeNotAllowed => {
+                "The url scheme '{scheme}' is not allowed. Allowed schemes are {allowed_schemes} and the default scheme is '{default_scheme}'."
+            }
+            MessageKey::RepositoryLinkMalformed => {
+                "The provided repository link [{repo_link}] is malformed. {reason}"
+            }
+            MessageKey::RepositoryLinkMissingHost => {
+                "The repository link [{repo_link}] has no host. Please provide a link with a host, such as [scheme]://[host]/[path]."
+            }
+            MessageKey::RepositoryLinkInvalidHost => {
+                "The repository link [{repo_link}] has the host '{host}', which contains whitespace or percent-encoded characters. Please provide a valid host name."
+            }
+            MessageKey::FieldInvalid => "The value for '{field}' is invalid. {reason}",
+            MessageKey::FieldExceedsMax => {
+                "The value for '{field}' is {actual}, which exceeds the maximum of {max}."
+            }
+            MessageKey::EntityNotFound => "The {entity} '{id}' was not found.",
+        }
+    }
+
+    /// Creates an [`Error`] for this key with the default English message.
+    ///
+    /// The key and `args` are recorded on the error, outside its context, so
+    /// [`Messages::render_error`] can render the same error again with a different template.
+    pub fn error(self, audience: Audience, kind: Kind, args: &[(&str, &str)]) -> Error {
+        Error::new(audience, kind, DefaultMessages.render(self, args)).with_origin(MessageOrigin {
+            key: Some(self),
+            args: args
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        })
+    }
+}
+
+/// Renders [`MessageKey`] messages, allowing consumers to replace the default English text.
+///
+/// Implementors usually only override [`Messages::template`]; the provided methods handle
+/// placeholder substitution and reading keys back out of an [`Error`].
+pub trait Messages {
+    /// Returns the template for `key`, with `{name}` placeholders for substituted values.
+    fn template(&self, key: MessageKey) -> String {
+        key.default_template().to_string()
+    }
+
+    /// Renders `key`, replacing each `{name}` placeholder with the matching value from `args`.
+    ///
+    /// Placeholders without a matching argument are left as written.
+    fn render(&self, key: MessageKey, args: &[(&str, &str)]) -> String {
+        fill_template(&self.template(key), args)
+    }
+
+    /// Renders `error` from its recorded [`MessageKey`] and arguments.
+    ///
+    /// Errors without a [`MessageKey`] render as their existing `message`.
+    fn render_error(&self, error: &Error) -> String {
+        match error.message_key() {
+            Some(key) => {
+                let args: Vec<(&str, &str)> = error
+                    .message_args()
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str()))
+                    .collect();
+                self.render(key, &args)
+            }
+            None => error.message.clone(),
+        }
+    }
+}
+
+/// The built-in English [`Messages`] renderer.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultMessages;
+
+impl Messages for DefaultMessages {}
+
+/// Replaces `{name}` placeholders in a single pass, so substituted values are never re-expanded.
+fn fill_template(template: &str, args: &[(&str, &str)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        rendered.push_str(&rest[..open]);
+        let after_open = &rest[open + 1..];
+        let value = after_open.find('}').and_then(|close| {
+            args.iter()
+                .find(|(name, _)| *name == &after_open[..close])
+                .map(|(_, value)| (close, *value))
+        });
+
+        match value {
+            Some((close, value)) => {
+                rendered.push_str(value);
+                rest = &after_open[close + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = after_open;
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
@@ -0,0 +1,186 @@
+//! Verifies the builder message keys and renderers.
+//!
+//! Bounded unit under test:
+//! - `MessageKey`
+//! - `Messages`
+//!
+//! Public interfaces verified:
+//! - `MessageKey::as_str`
+//! - `MessageKey::from_key_str`
+//! - `MessageKey::error`
+//! - `Error::message_key` and `Error::message_args`
+//! - `Messages::render`
+//! - `Messages::render_error`
+//! - `DefaultMessages`
+//!
+//! Logical paths covered:
+//! - every key round-trips through its stable string form
+//! - default rendering matches the existing `FileName` and `RepositoryLink` messages
+//! - an overriding renderer changes the text for overridden keys only
+//! - errors without a message key render as their existing message
+//! - keyed errors equal plain errors with the same text, and user context does not change how
+//!   they render
+//! - unknown placeholders are left as written and values are not re-expanded
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::{DefaultMessages, MessageKey, Messages};
+use crate::error::{Audience, Error, Kind};
+use crate::values::file_system::file_name::FileName;
+use crate::values::specification::repository_link::RepositoryLink;
+use test_framework_oss::is_error;
+
+struct SpanishMessages;
+
+impl Messages for SpanishMessages {
+    fn template(&self, key: MessageKey) -> String {
+        match key {
+            MessageKey::FileNameEmpty => "El nombre del archivo no puede estar vacío.".to_string(),
+            MessageKey::RepositoryLinkSchemeNotAllowed => {
+                "El esquema '{scheme}' no está permitido.".to_string()
+            }
+            _ => key.default_template().to_string(),
+        }
+    }
+}
+
+fn scheme_not_allowed_error() -> Error {
+    is_error!(
+        RepositoryLink::builder()
+            .allowed_schema(vec!["git".to_string(), "https".to_string()])
+            .default_scheme("git")
+            .repo_link("ssh://github.com/nape/processes")
+            .build()
+    )
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that every key round-trips through its stable string form.
+#[test]
+fn key_string_round_trip_success() {
+    for key in MessageKey::ALL {
+        assert_eq!(MessageKey::from_key_str(key.as_str()), Some(key));
+    }
+    assert_eq!(MessageKey::from_key_str("unknown.key"), None);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that builder errors keep their existing default English messages
+/// and record their message key.
+#[test]
+fn default_messages_match_existing_success() {
+    let file_name_error = is_error!(FileName::builder().value("  ").build());
+    assert_eq!(file_name_error.message, "The file name cannot be empty.");
+    assert_eq!(file_name_error.audience, Audience::System);
+    assert_eq!(
+        file_name_error.message_key(),
+        Some(MessageKey::FileNameEmpty)
+    );
+    assert!(file_name_error.context().is_empty());
+    assert_eq!(
+        file_name_error,
+        Error::for_system(Kind::InvalidInput, "The file name cannot be empty.")
+    );
+    assert_eq!(
+        DefaultMessages.render_error(&file_name_error),
+        file_name_error.message
+    );
+
+    let link_error = scheme_not_allowed_error();
+    assert_eq!(
+        link_error.message,
+        "The url scheme 'ssh' is not allowed. Allowed schemes are [\"git\", \"https\"] and the default scheme is 'git'."
+    );
+    assert_eq!(
+        DefaultMessages.render_error(&link_error),
+        link_error.message
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an overriding renderer changes the text of overridden keys and
+/// leaves other keys at their defaults.
+#[test]
+fn overriding_renderer_success() {
+    let file_name_error = is_error!(FileName::builder().value("").build());
+    assert_eq!(
+        SpanishMessages.render_error(&file_name_error),
+        "El nombre del archivo no puede estar vacío."
+    );
+
+    assert_eq!(
+        SpanishMessages.render_error(&scheme_not_allowed_error()),
+        "El esquema 'ssh' no está permitido."
+    );
+
+    let dot_error = is_error!(FileName::builder().value("..").build());
+    assert_eq!(
+        SpanishMessages.render_error(&dot_error),
+        "The file name cannot be '.' or '..'."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an error without a message key renders as its existing message.
+#[test]
+fn render_error_without_key_success() {
+    let error = Error::for_user(Kind::NotFound, "not found").with_context("id", "42");
+
+    assert_eq!(SpanishMessages.render_error(&error), "not found");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that unknown placeholders are kept and substituted values are not
+/// expanded again.
+#[test]
+fn render_placeholders_success() {
+    let error = MessageKey::RepositoryLinkMalformed.error(
+        Audience::System,
+        Kind::InvalidInput,
+        &[("repo_link", "{reason}")],
+    );
+
+    assert_eq!(
+        error.message,
+        "The provided repository link [{reason}] is malformed. {reason}"
+    );
+    assert_eq!(
+        error.message_args(),
+        [("repo_link".to_string(), "{reason}".to_string())]
+    );
+    assert!(error.context().is_empty());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that context a caller attaches with the same names as template arguments neither
+/// changes how a keyed error renders nor appears among its message arguments.
+#[test]
+fn render_error_ignores_user_context_success() {
+    let error = MessageKey::FieldInvalid
+        .error(
+            Audience::User,
+            Kind::InvalidInput,
+            &[("field", "name"), ("reason", "It is empty.")],
+        )
+        .with_context("field", "other")
+        .with_context("reason", "overridden");
+
+    assert_eq!(
+        SpanishMessages.render_error(&error),
+        "The value for 'name' is invalid. It is empty."
+    );
+    assert_eq!(
+        error.message_args(),
+        [
+            ("field".to_string(), "name".to_string()),
+            ("reason".to_string(), "It is empty.".to_string()),
+        ]
+    );
+}
@@ -3,6 +3,8 @@
 //! Use [`Error`] for public fallible APIs so callers can distinguish
 //! user-facing input errors from system or operational failures.
 
+pub mod message;
+
 #[cfg(test)]
 mod tests;
 
@@ -11,12 +13,14 @@ mod tests;
 /// The `Error` type carries:
 /// - `audience`: who should handle the error (user vs system),
 /// - `kind`: a coarse category of error,
-/// - `message`: a human-readable description stored as an owned `String`.
+/// - `message`: a human-readable description stored as an owned `String`,
+/// - `context`: ordered key/value pairs attached while the error propagates, for structured logs.
 ///
 /// Purpose:
 /// - Provide a lightweight, copyable error payload that can be matched on by callers,
 /// - Be usable in collections (`Hash`, `Eq`) and for diagnostics (`Debug`, `Display`),
-/// - Keep error handling simple: no backtrace or source chaining here, only classification + message.
+/// - Keep error handling simple: no backtrace or source chaining here, only classification,
+///   message, and optional context.
 ///
 /// Guarantees and design notes:
 /// - `Error` derives `Clone`, `Debug`, `Eq`, `Hash`, and `PartialEq` so it can be cloned,
@@ -24,14 +28,32 @@ mod tests;
 /// - Ordering (`Ord`/`PartialOrd`) is intentionally not relied on by callers; comparisons
 ///   should match on `audience`/`kind`/`message` explicitly when needed.
 /// - `message` is an owned `String` so callers do not need to retain the originating input.
+/// - `context` keeps insertion order and takes part in `Eq` and `Hash`, so two errors that differ
+///   only in context are not equal.
+/// - The [`message::MessageKey`] an error was rendered from is kept apart from `context` and does
+///   not take part in `Eq`, `Hash`, or serialization, so keyed errors still equal plain errors
+///   with the same audience, kind, and message.
 ///
 /// Public interfaces:
 /// - `Error::new(audience, kind, message)`: construct any `Error`.
 /// - `Error::for_user(kind, message)`: convenience constructor for user-facing errors.
 /// - `Error::for_system(kind, message)`: convenience constructor for system-facing errors.
+/// - `Error::invalid_input_field`, `Error::not_found_entity`, and `Error::exceeds_max`:
+///   user-facing errors with consistently worded messages from [`message::MessageKey`].
 /// - `Error::is_user() -> bool` / `Error::is_system() -> bool`: quick audience checks.
-/// - `Display` is implemented to format the `message` only (suitable for end-user display).
+/// - `Error::is_retryable() -> bool`: whether repeating the failed call may succeed.
+/// - `Error::with_context(key, value)`: attach a context entry and return the error.
+/// - `Error::message_key()` / `Error::message_args()`: the message key and arguments a keyed
+///   error was rendered from.
+/// - `Error::combine(other)`: report two independent failures as one system error.
+/// - `Error::sort_key() -> (u8, u8, &str)`: deterministic ordering key, without making `Error` `Ord`.
+/// - `Display` is implemented to format the `message` only (suitable for end-user display);
+///   `Debug` includes the context.
+/// - With the `serde` feature, `Error` serializes as an object with snake_case `audience` and
+///   `kind` strings, the `message`, and `context` as `[key, value]` pairs. `context` is omitted
+///   when empty and defaults to empty when absent.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
     /// Audience classification for the error.
     pub audience: Audience,
@@ -39,6 +61,16 @@ pub struct Error {
     pub kind: Kind,
     /// Human-readable error message.
     pub message: String,
+    /// Key/value context entries, in the order they were attached.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    context: Vec<(String, String)>,
+    /// The message key and arguments the message was rendered from, if any. Not part of
+    /// equality, hashing, or serialization.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    origin: message::MessageOrigin,
 }
 
 impl Error {
@@ -57,6 +89,60 @@ impl Error {
         &self.message
     }
 
+    /// Returns the context entries in the order they were attached.
+    pub fn context(&self) -> &[(String, String)] {
+        &self.context
+    }
+
+    /// Returns the [`MessageKey`](message::MessageKey) the message was rendered from, if any.
+    pub fn message_key(&self) -> Option<message::MessageKey> {
+        self.origin.key
+    }
+
+    /// Returns the values substituted into the message key's template, in the order given.
+    ///
+    /// Empty for errors that were not created through a [`MessageKey`](message::MessageKey).
+    pub fn message_args(&self) -> &[(String, String)] {
+        &self.origin.args
+    }
+
+    pub(crate) fn with_origin(mut self, origin: message::MessageOrigin) -> Error {
+        self.origin = origin;
+        self
+    }
+
+    /// Returns a key for deterministic sorting, such as
+    /// `errors.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()))`.
+    ///
+    /// The key borrows the message, so use `sort_by` rather than `sort_by_key`, which cannot
+    /// return borrowed keys.
+    ///
+    /// The key is the declaration index of `audience`, then of `kind`, then the message, so sorted
+    /// errors group by audience and then by kind. The indices are only meant for ordering within
+    /// one build; they shift if variants are added, so do not persist them.
+    pub fn sort_key(&self) -> (u8, u8, &str) {
+        (self.audience as u8, self.kind as u8, &self.message)
+    }
+
+    /// Attaches a key/value context entry and returns the error, so context can be added as the
+    /// error propagates without rewrapping it.
+    ///
+    /// Example:
+    /// ```rust
+    /// use kernel_oss::error::{Error, Kind};
+    ///
+    /// let e = Error::for_system(Kind::GatewayError, "read failed")
+    ///     .with_context("path", "evidence/report.json")
+    ///     .with_context("attempt", "2");
+    ///
+    /// assert_eq!(e.context().len(), 2);
+    /// assert_eq!(e.to_string(), "read failed");
+    /// ```
+    pub fn with_context(mut self, key: impl Into<String>, value: impl Into<String>) -> Error {
+        self.context.push((key.into(), value.into()));
+        self
+    }
+
     /// Create a new `Error` with the given audience, kind and message.
     ///
     /// `message` accepts any type that implements `Into<String>` for ergonomic callers.
@@ -74,6 +160,8 @@ impl Error {
             audience,
             kind,
             message: message.into(),
+            context: Vec::new(),
+            origin: message::MessageOrigin::default(),
         }
     }
 
@@ -91,6 +179,52 @@ impl Error {
         Error::new(Audience::System, kind, message)
     }
 
+    /// Creates a user-facing [`Kind::InvalidInput`] error for the named field.
+    ///
+    /// The message is rendered from [`MessageKey::FieldInvalid`](message::MessageKey::FieldInvalid),
+    /// so [`Error::message_key`] and [`Error::message_args`] return the key, field, and reason
+    /// for re-rendering.
+    ///
+    /// Example:
+    /// ```rust
+    /// use kernel_oss::error::{Error, Kind};
+    ///
+    /// let e = Error::invalid_input_field("name", "A name cannot be empty.");
+    /// assert_eq!(e.kind, Kind::InvalidInput);
+    /// assert_eq!(e.message, "The value for 'name' is invalid. A name cannot be empty.");
+    /// ```
+    pub fn invalid_input_field(field: &str, reason: &str) -> Error {
+        message::MessageKey::FieldInvalid.error(
+            Audience::User,
+            Kind::InvalidInput,
+            &[("field", field), ("reason", reason)],
+        )
+    }
+
+    /// Creates a user-facing [`Kind::NotFound`] error for an entity that has no match for `id`,
+    /// such as `Error::not_found_entity("activity", "build")`.
+    pub fn not_found_entity(entity: &str, id: &str) -> Error {
+        message::MessageKey::EntityNotFound.error(
+            Audience::User,
+            Kind::NotFound,
+            &[("entity", entity), ("id", id)],
+        )
+    }
+
+    /// Creates a user-facing [`Kind::ExceedsMax`] error for a field whose `actual` value, such
+    /// as a length or count, is larger than `max`.
+    pub fn exceeds_max(field: &str, max: usize, actual: usize) -> Error {
+        message::MessageKey::FieldExceedsMax.error(
+            Audience::User,
+            Kind::ExceedsMax,
+            &[
+                ("field", field),
+                ("max", &max.to_string()),
+                ("actual", &actual.to_string()),
+            ],
+        )
+    }
+
     /// Returns `true` when the error is intended for a user-level audience.
     pub fn is_user(&self) -> bool {
         self.audience == Audience::User
@@ -100,6 +234,55 @@ impl Error {
     pub fn is_system(&self) -> bool {
         self.audience == Audience::System
     }
+
+    /// Returns `true` when repeating the failed call may succeed.
+    ///
+    /// Only [`Kind::GatewayError`] is retryable, since gateway failures cross an external boundary
+    /// and may be transient. Every other kind describes input or local state that will fail the
+    /// same way on a repeated call.
+    pub fn is_retryable(&self) -> bool {
+        self.kind == Kind::GatewayError
+    }
+
+    /// Combines two independent failures, such as a primary and a fallback call that both failed,
+    /// into one error that reports both.
+    ///
+    /// The message is `"{self}; also: {other}"` and the context entries of `self` come before
+    /// those of `other`. The result is always for [`Audience::System`], since a caller that tried
+    /// two paths needs to investigate both. The more severe kind is kept, ranked by
+    /// [`Kind::severity`] so a local failure outranks an upstream one and both outrank an input
+    /// error; on a tie the kind of `self` is kept.
+    ///
+    /// Example:
+    /// ```rust
+    /// use kernel_oss::error::{Audience, Error, Kind};
+    ///
+    /// let primary = Error::for_user(Kind::NotFound, "the primary mirror has no such file");
+    /// let fallback = Error::for_system(Kind::GatewayError, "the fallback mirror timed out");
+    ///
+    /// let combined = primary.combine(fallback);
+    /// assert_eq!(combined.audience, Audience::System);
+    /// assert_eq!(combined.kind, Kind::GatewayError);
+    /// assert_eq!(
+    ///     combined.message,
+    ///     "the primary mirror has no such file; also: the fallback mirror timed out"
+    /// );
+    /// ```
+    pub fn combine(mut self, other: Error) -> Error {
+        let kind = if other.kind.severity() > self.kind.severity() {
+            other.kind
+        } else {
+            self.kind
+        };
+        self.context.extend(other.context);
+        Error {
+            audience: Audience::System,
+            kind,
+            message: format!("{}; also: {}", self.message, other.message),
+            context: self.context,
+            origin: message::MessageOrigin::default(),
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -112,6 +295,81 @@ impl std::fmt::Display for Error {
     }
 }
 
+/// An ordered collection of [`Error`]s for flows that report every problem at once.
+///
+/// Validation that checks several fields can [`Errors::push`] each failure and finish with
+/// [`Errors::into_result`], instead of returning on the first error. `Display` joins the
+/// messages with newlines in the order they were pushed.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Errors {
+    /// The collected errors, in the order they were pushed.
+    errors: Vec<Error>,
+}
+
+impl Errors {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Errors { errors: Vec::new() }
+    }
+
+    /// Appends `error` to the collection.
+    pub fn push(&mut self, error: Error) {
+        self.errors.push(error);
+    }
+
+    /// Returns `true` when no error has been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of collected errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns the collected errors in the order they were pushed.
+    pub fn as_slice(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Returns `Ok(ok)` when the collection is empty, otherwise `Err(self)`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use kernel_oss::error::{Error, Errors, Kind};
+    ///
+    /// let mut errors = Errors::new();
+    /// errors.push(Error::for_user(Kind::InvalidInput, "name is empty"));
+    /// errors.push(Error::for_user(Kind::ExceedsMax, "path is too long"));
+    ///
+    /// let result = errors.into_result(());
+    /// assert_eq!(
+    ///     result.map_err(|errors| errors.to_string()),
+    ///     Err("name is empty\npath is too long".to_string())
+    /// );
+    /// ```
+    pub fn into_result<T>(self, ok: T) -> Result<T, Errors> {
+        if self.errors.is_empty() {
+            Ok(ok)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl std::fmt::Display for Errors {
+    /// Displays each error's message on its own line, without a trailing newline.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
 /// Specifies who should handle an `Error`.
 ///
 /// It is used with the [`Error`] type.
@@ -122,6 +380,8 @@ impl std::fmt::Display for Error {
 /// - `System` indicates the error is for internal/operational handling (logs, metrics).
 /// - Do not rely on any ordering of variants; use equality or pattern matching as needed.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Audience {
     /// Intended for end users.
     User,
@@ -129,6 +389,46 @@ pub enum Audience {
     System,
 }
 
+impl Audience {
+    /// Returns the stable lowercase name of the audience, `"user"` or `"system"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Audience::User => "user",
+            Audience::System => "system",
+        }
+    }
+}
+
+impl std::fmt::Display for Audience {
+    /// Displays the stable lowercase name returned by [`Audience::as_str`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Audience {
+    type Err = Error;
+
+    /// Parses the form returned by [`Audience::as_str`], ignoring ASCII case.
+    ///
+    /// Any other value is a system-facing [`Kind::InvalidInput`] error.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("user") {
+            Ok(Audience::User)
+        } else if value.eq_ignore_ascii_case("system") {
+            Ok(Audience::System)
+        } else {
+            Err(Error::for_system(
+                Kind::InvalidInput,
+                format!(
+                    "The audience '{}' is not recognized. Expected 'user' or 'system'.",
+                    value
+                ),
+            ))
+        }
+    }
+}
+
 /// High-level categories for `Error` instances.
 ///
 /// It is used with the [`Error`] type.
@@ -138,7 +438,11 @@ pub enum Audience {
 ///   and use `_` to handle other/unknown cases.
 /// - Treat `Kind` as a classification for routing or mapping to user messages/log levels.
 /// - Do not rely on ordering of variants; use explicit matches or equality checks when needed.
+/// - With the `serde` feature, kinds serialize as snake_case strings such as `"invalid_input"`,
+///   and unknown strings fail to deserialize.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Kind {
     /// A value exceeds the maximum allowed length.
     ExceedsMax,
@@ -158,4 +462,54 @@ pub enum Kind {
     PermissionDenied,
     /// A local processing failure occurred.
     ProcessingFailure,
+    /// The input conflicts with existing state, such as two definitions under the same name.
+    Conflict,
+}
+
+impl Kind {
+    /// Returns the HTTP status code a service should respond with for this kind.
+    ///
+    /// - [`Kind::InvalidInput`], [`Kind::ExceedsMax`], and [`Kind::BelowMin`] map to `400 Bad Request`,
+    ///   since the caller sent a value that does not meet its constraints.
+    /// - [`Kind::NotFound`] maps to `404 Not Found`.
+    /// - [`Kind::PermissionDenied`] maps to `403 Forbidden`.
+    /// - [`Kind::Conflict`] maps to `409 Conflict`.
+    /// - [`Kind::GatewayError`] maps to `502 Bad Gateway`, since an upstream dependency failed.
+    /// - [`Kind::UsecaseError`], [`Kind::ProcessingFailure`], and [`Kind::Unexpected`] map to
+    ///   `500 Internal Server Error`.
+    ///
+    /// The match is exhaustive on purpose, so adding a `Kind` requires choosing its status here.
+    pub fn http_status(&self) -> u16 {
+        match self {
+            Kind::InvalidInput | Kind::ExceedsMax | Kind::BelowMin => 400,
+            Kind::NotFound => 404,
+            Kind::PermissionDenied => 403,
+            Kind::Conflict => 409,
+            Kind::GatewayError => 502,
+            Kind::UsecaseError | Kind::ProcessingFailure | Kind::Unexpected => 500,
+        }
+    }
+
+    /// Returns how severe this kind is, from `0` for the least severe, for choosing between two
+    /// failures as [`Error::combine`] does.
+    ///
+    /// - [`Kind::Unexpected`] is `4`, since nothing is known about its cause.
+    /// - [`Kind::UsecaseError`] and [`Kind::ProcessingFailure`] are `3`, a known local failure.
+    /// - [`Kind::GatewayError`] is `2`, since an upstream failure may be transient.
+    /// - [`Kind::NotFound`], [`Kind::PermissionDenied`], and [`Kind::Conflict`] are `1`, a
+    ///   request that does not fit the current state.
+    /// - [`Kind::InvalidInput`], [`Kind::ExceedsMax`], and [`Kind::BelowMin`] are `0`, a value the
+    ///   caller can correct.
+    ///
+    /// The order is independent of [`Kind::http_status`], where a gateway failure has the larger
+    /// code. The match is exhaustive on purpose, so adding a `Kind` requires ranking it here.
+    pub fn severity(&self) -> u8 {
+        match self {
+            Kind::Unexpected => 4,
+            Kind::UsecaseError | Kind::ProcessingFailure => 3,
+            Kind::GatewayError => 2,
+            Kind::NotFound | Kind::PermissionDenied | Kind::Conflict => 1,
+            Kind::InvalidInput | Kind::ExceedsMax | Kind::BelowMin => 0,
+        }
+    }
 }
@@ -2,20 +2,44 @@
 //!
 //! Bounded unit under test:
 //! - `Error`
+//! - `Errors`
 //!
 //! Public interfaces verified:
 //! - `Error::new`
 //! - `Error::for_user`
 //! - `Error::for_system`
+//! - `Error::invalid_input_field`, `Error::not_found_entity`, and `Error::exceeds_max`
+//! - `Error::is_retryable`
+//! - `Error::with_context` and `Error::context`
+//! - `Kind::http_status`
+//! - `Kind::severity`
+//! - `Audience::as_str`, `Display` for `Audience`, and `Audience::from_str`
+//! - `Error::sort_key`
+//! - `Error::combine`
 //! - `Display` and equality/hash behavior
+//! - feature-gated serde implementations for `Error`, `Audience`, and `Kind`
+//! - `Errors::push`, `Errors::is_empty`, `Errors::len`, `Errors::as_slice`, and `Errors::into_result`
+//! - `Display` for `Errors`
 //!
 //! Logical paths covered:
 //! - error construction stores audience, kind, and message
 //! - convenience constructors set the expected audience
+//! - templated constructors set the kind, audience, message, and message-key context
 //! - clone, equality, and hash remain consistent
 //! - hash-based lookup accepts equal errors
 //! - debug formatting remains available
 //! - empty error messages remain representable
+//! - only gateway errors are retryable
+//! - every kind maps to its HTTP status code
+//! - audiences round-trip through their string and display forms, parse ignoring case, and unknown strings are rejected
+//! - sorting by the sort key groups by audience then kind and is stable for equal keys
+//! - context entries round-trip in order, show in debug output, and affect equality
+//! - combining two errors yields a system error with both messages, both contexts, and the more severe kind
+//! - severity ranks an unexpected error above a gateway error regardless of HTTP status
+//! - errors round-trip through JSON with snake_case audience and kind, including empty messages and context
+//! - unknown kind strings fail to deserialize
+//! - an empty error collection converts to `Ok` and a populated one to `Err`
+//! - an error collection displays its messages joined by newlines
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
@@ -24,7 +48,11 @@ use std::collections::HashSet;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-use super::{Audience, Error, Kind};
+use super::message::MessageKey;
+use super::{Audience, Error, Errors, Kind};
+#[cfg(feature = "serde")]
+use test_framework_oss::is_error;
+use test_framework_oss::{is_ok, kernel_error_eq};
 
 /// Requirement validation: No requirement validation point is currently supplied.
 ///
@@ -108,3 +136,358 @@ fn empty_message_is_allowed_success() {
     assert_eq!(format!("{}", e), "");
     assert!(e.is_user());
 }
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that only gateway errors are retryable, regardless of audience.
+#[test]
+fn is_retryable_success() {
+    assert!(Error::for_system(Kind::GatewayError, "timeout").is_retryable());
+    assert!(Error::for_user(Kind::GatewayError, "unavailable").is_retryable());
+    assert!(!Error::for_system(Kind::Unexpected, "oh no").is_retryable());
+    assert!(!Error::for_system(Kind::ProcessingFailure, "failed").is_retryable());
+    assert!(!Error::for_user(Kind::InvalidInput, "bad input").is_retryable());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that every kind maps to its documented HTTP status code.
+#[test]
+fn http_status_success() {
+    assert_eq!(Kind::InvalidInput.http_status(), 400);
+    assert_eq!(Kind::ExceedsMax.http_status(), 400);
+    assert_eq!(Kind::BelowMin.http_status(), 400);
+    assert_eq!(Kind::NotFound.http_status(), 404);
+    assert_eq!(Kind::PermissionDenied.http_status(), 403);
+    assert_eq!(Kind::Conflict.http_status(), 409);
+    assert_eq!(Kind::GatewayError.http_status(), 502);
+    assert_eq!(Kind::UsecaseError.http_status(), 500);
+    assert_eq!(Kind::ProcessingFailure.http_status(), 500);
+    assert_eq!(Kind::Unexpected.http_status(), 500);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that context entries round-trip in order and appear in the debug
+/// output but not the display output.
+#[test]
+fn with_context_round_trip_success() {
+    let e = Error::for_system(Kind::GatewayError, "read failed")
+        .with_context("path", "evidence/report.json")
+        .with_context("attempt", "2");
+
+    assert_eq!(
+        e.context(),
+        [
+            ("path".to_string(), "evidence/report.json".to_string()),
+            ("attempt".to_string(), "2".to_string()),
+        ]
+    );
+    assert_eq!(e.to_string(), "read failed");
+    assert!(format!("{:?}", e).contains("evidence/report.json"));
+    assert!(
+        Error::for_system(Kind::GatewayError, "read failed")
+            .context()
+            .is_empty()
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that errors differing only in context are unequal and hash
+/// differently, while equal context hashes the same.
+#[test]
+fn with_context_equality_and_hash_success() {
+    let hash = |error: &Error| {
+        let mut hasher = DefaultHasher::new();
+        error.hash(&mut hasher);
+        hasher.finish()
+    };
+    let base = Error::for_user(Kind::InvalidInput, "bad input");
+    let a = base.clone().with_context("field", "name");
+    let b = base.clone().with_context("field", "name");
+    let c = base.clone().with_context("field", "description");
+
+    assert_ne!(base, a);
+    assert_ne!(a, c);
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&c));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that both audiences round-trip through their string and display forms, and that
+/// parsing ignores ASCII case.
+#[test]
+fn audience_str_round_trip_success() {
+    assert_eq!(Audience::User.as_str(), "user");
+    assert_eq!(Audience::System.as_str(), "system");
+    assert_eq!(Audience::User.to_string(), "user");
+    assert_eq!(Audience::System.to_string(), "system");
+
+    for audience in [Audience::User, Audience::System] {
+        assert_eq!(is_ok!(audience.as_str().parse::<Audience>()), audience);
+        assert_eq!(is_ok!(audience.to_string().parse::<Audience>()), audience);
+    }
+    assert_eq!(is_ok!("User".parse::<Audience>()), Audience::User);
+    assert_eq!(is_ok!("SYSTEM".parse::<Audience>()), Audience::System);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an unknown audience string is rejected.
+#[test]
+fn audience_from_str_unknown_error() {
+    kernel_error_eq!(
+        "admin".parse::<Audience>(),
+        Kind::InvalidInput,
+        Audience::System,
+        "The audience 'admin' is not recognized. Expected 'user' or 'system'."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that sorting by `sort_key` groups errors by audience then kind, orders messages
+/// within a group, and keeps errors with equal keys in their original order.
+#[test]
+fn sort_key_groups_and_is_stable_success() {
+    let mut errors = [
+        Error::for_system(Kind::NotFound, "b"),
+        Error::for_user(Kind::InvalidInput, "z"),
+        Error::for_system(Kind::ExceedsMax, "a"),
+        Error::for_user(Kind::ExceedsMax, "m").with_context("order", "first"),
+        Error::for_user(Kind::InvalidInput, "a"),
+        Error::for_user(Kind::ExceedsMax, "m").with_context("order", "second"),
+    ];
+
+    errors.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+
+    let sorted: Vec<(Audience, Kind, &str)> = errors
+        .iter()
+        .map(|error| (error.audience, error.kind, error.message.as_str()))
+        .collect();
+    assert_eq!(
+        sorted,
+        [
+            (Audience::User, Kind::ExceedsMax, "m"),
+            (Audience::User, Kind::ExceedsMax, "m"),
+            (Audience::User, Kind::InvalidInput, "a"),
+            (Audience::User, Kind::InvalidInput, "z"),
+            (Audience::System, Kind::ExceedsMax, "a"),
+            (Audience::System, Kind::NotFound, "b"),
+        ]
+    );
+    assert_eq!(errors[0].context()[0].1, "first");
+    assert_eq!(errors[1].context()[0].1, "second");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that combining a user error with a system error yields a system error carrying both
+/// messages, both contexts in order, and the more severe kind regardless of argument order.
+#[test]
+fn combine_user_and_system_success() {
+    let user = Error::for_user(Kind::InvalidInput, "primary failed").with_context("mirror", "a");
+    let system =
+        Error::for_system(Kind::GatewayError, "fallback failed").with_context("mirror", "b");
+
+    let combined = user.clone().combine(system.clone());
+    assert_eq!(combined.audience, Audience::System);
+    assert_eq!(combined.kind, Kind::GatewayError);
+    assert_eq!(combined.message, "primary failed; also: fallback failed");
+    assert_eq!(
+        combined.context(),
+        [
+            ("mirror".to_string(), "a".to_string()),
+            ("mirror".to_string(), "b".to_string()),
+        ]
+    );
+
+    let reversed = system.combine(user);
+    assert_eq!(reversed.audience, Audience::System);
+    assert_eq!(reversed.kind, Kind::GatewayError);
+    assert_eq!(reversed.message, "fallback failed; also: primary failed");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an unexpected error outranks a gateway error in either order, even though the
+/// gateway error has the larger HTTP status.
+#[test]
+fn combine_unexpected_outranks_gateway_success() {
+    let gateway = Error::for_system(Kind::GatewayError, "upstream failed");
+    let unexpected = Error::for_system(Kind::Unexpected, "state was corrupt");
+
+    assert!(Kind::GatewayError.http_status() > Kind::Unexpected.http_status());
+    assert_eq!(
+        gateway.clone().combine(unexpected.clone()).kind,
+        Kind::Unexpected
+    );
+    assert_eq!(unexpected.combine(gateway).kind, Kind::Unexpected);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that two user errors still combine into a system error and that a tie in severity
+/// keeps the kind of the first error.
+#[test]
+fn combine_equal_severity_success() {
+    let combined = Error::for_user(Kind::ExceedsMax, "too long")
+        .combine(Error::for_user(Kind::InvalidInput, "bad character"));
+
+    assert_eq!(combined.audience, Audience::System);
+    assert_eq!(combined.kind, Kind::ExceedsMax);
+    assert_eq!(combined.to_string(), "too long; also: bad character");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that errors round-trip through JSON with snake_case audience and kind strings,
+/// omitting empty context and keeping context pairs in order.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip_success() {
+    let error = Error::for_system(Kind::GatewayError, "upstream failed")
+        .with_context("attempt", "2")
+        .with_context("path", "/tmp/a");
+
+    let json = is_ok!(serde_json::to_string(&error));
+    assert_eq!(
+        json,
+        r#"{"audience":"system","kind":"gateway_error","message":"upstream failed","context":[["attempt","2"],["path","/tmp/a"]]}"#
+    );
+    assert_eq!(is_ok!(serde_json::from_str::<Error>(&json)), error);
+
+    let empty = Error::for_user(Kind::InvalidInput, "");
+    let json = is_ok!(serde_json::to_string(&empty));
+    assert_eq!(
+        json,
+        r#"{"audience":"user","kind":"invalid_input","message":""}"#
+    );
+    assert_eq!(is_ok!(serde_json::from_str::<Error>(&json)), empty);
+
+    for audience in [Audience::User, Audience::System] {
+        assert_eq!(
+            is_ok!(serde_json::to_string(&audience)),
+            format!("\"{}\"", audience.as_str())
+        );
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an unknown kind string fails to deserialize.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_unknown_kind_error() {
+    let result =
+        serde_json::from_str::<Error>(r#"{"audience":"user","kind":"teapot","message":"short"}"#);
+
+    assert!(
+        is_error!(result)
+            .to_string()
+            .contains("unknown variant `teapot`")
+    );
+    assert!(serde_json::from_str::<Kind>(r#""InvalidInput""#).is_err());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an empty error collection converts to `Ok` with the supplied value.
+#[test]
+fn errors_empty_into_result_success() {
+    let errors = Errors::new();
+
+    assert!(errors.is_empty());
+    assert_eq!(errors.len(), 0);
+    assert_eq!(is_ok!(errors.into_result(7)), 7);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a populated error collection converts to `Err` holding every pushed error in
+/// order.
+#[test]
+fn errors_populated_into_result_error() {
+    let first = Error::for_user(Kind::InvalidInput, "name is empty");
+    let second = Error::for_system(Kind::ExceedsMax, "path is too long");
+    let mut errors = Errors::new();
+    errors.push(first.clone());
+    errors.push(second.clone());
+
+    assert!(!errors.is_empty());
+    assert_eq!(errors.len(), 2);
+    match errors.into_result(()) {
+        Ok(()) => panic!("expected the populated collection to be an error"),
+        Err(errors) => assert_eq!(errors.as_slice(), [first, second]),
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an error collection displays one message per line with no trailing newline,
+/// and that an empty collection displays as an empty string.
+#[test]
+fn errors_display_joins_messages_success() {
+    let mut errors = Errors::new();
+    assert_eq!(errors.to_string(), "");
+
+    errors.push(Error::for_user(Kind::InvalidInput, "name is empty"));
+    assert_eq!(errors.to_string(), "name is empty");
+
+    errors
+        .push(Error::for_user(Kind::ExceedsMax, "path is too long").with_context("field", "path"));
+    assert_eq!(errors.to_string(), "name is empty\npath is too long");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the templated constructors produce consistent messages with the expected kind
+/// and audience, record their message key and arguments outside the context, and equal a plain
+/// error with the same text.
+#[test]
+fn templated_constructors_success() {
+    let invalid = Error::invalid_input_field("name", "A name cannot be empty.");
+    assert_eq!(invalid.kind, Kind::InvalidInput);
+    assert_eq!(invalid.audience, Audience::User);
+    assert_eq!(
+        invalid.message,
+        "The value for 'name' is invalid. A name cannot be empty."
+    );
+    assert_eq!(invalid.message_key(), Some(MessageKey::FieldInvalid));
+    assert_eq!(
+        invalid.message_args(),
+        [
+            ("field".to_string(), "name".to_string()),
+            ("reason".to_string(), "A name cannot be empty.".to_string()),
+        ]
+    );
+    assert!(invalid.context().is_empty());
+    assert_eq!(
+        invalid,
+        Error::for_user(
+            Kind::InvalidInput,
+            "The value for 'name' is invalid. A name cannot be empty."
+        )
+    );
+    let plain: HashSet<Error> = HashSet::from([Error::for_user(
+        Kind::InvalidInput,
+        "The value for 'name' is invalid. A name cannot be empty.",
+    )]);
+    assert!(plain.contains(&invalid));
+
+    let not_found = Error::not_found_entity("activity", "build");
+    assert_eq!(not_found.kind, Kind::NotFound);
+    assert_eq!(not_found.audience, Audience::User);
+    assert_eq!(not_found.message, "The activity 'build' was not found.");
+
+    let exceeds = Error::exceeds_max("tags", 10, 12);
+    assert_eq!(exceeds.kind, Kind::ExceedsMax);
+    assert_eq!(exceeds.audience, Audience::User);
+    assert_eq!(
+        exceeds.message,
+        "The value for 'tags' is 12, which exceeds the maximum of 10."
+    );
+}
@@ -0,0 +1,99 @@
+//! Bundle of the legacy identity, UTC timestamp, and logger gateways.
+//!
+//! Use [`Gateways`] to pass the three legacy gateways an application wires
+//! together as one cloneable value.
+
+#![allow(deprecated)]
+
+#[cfg(test)]
+mod tests;
+
+use crate::error::{Error, Kind};
+use crate::gateway::identity::IdentityGateway;
+use crate::gateway::logger::Logger;
+use crate::gateway::utc_timestamp::UTCTimestampGateway;
+use std::sync::Arc;
+
+/// Holds an identity gateway, a UTC timestamp gateway, and a logger.
+///
+/// Cloning the bundle clones the boxed gateways through their `clone_box` support and shares the
+/// logger handle.
+#[derive(Clone)]
+pub struct Gateways {
+    identity: Box<dyn IdentityGateway>,
+    utc_timestamp: Box<dyn UTCTimestampGateway>,
+    logger: Arc<dyn Logger>,
+}
+
+impl Gateways {
+    /// Starts construction for a [`Gateways`] bundle.
+    pub fn builder() -> GatewaysBuilder {
+        GatewaysBuilder::default()
+    }
+
+    /// Returns the identity gateway.
+    pub fn identity(&self) -> &dyn IdentityGateway {
+        self.identity.as_ref()
+    }
+
+    /// Returns the UTC timestamp gateway.
+    pub fn utc_timestamp(&self) -> &dyn UTCTimestampGateway {
+        self.utc_timestamp.as_ref()
+    }
+
+    /// Returns the shared logger handle.
+    pub fn logger(&self) -> &Arc<dyn Logger> {
+        &self.logger
+    }
+}
+
+/// Builds a [`Gateways`] bundle.
+#[derive(Clone, Default)]
+pub struct GatewaysBuilder {
+    identity: Option<Box<dyn IdentityGateway>>,
+    utc_timestamp: Option<Box<dyn UTCTimestampGateway>>,
+    logger: Option<Arc<dyn Logger>>,
+}
+
+impl GatewaysBuilder {
+    /// Sets the identity gateway.
+    pub fn identity(mut self, identity: Box<dyn IdentityGateway>) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Sets the UTC timestamp gateway.
+    pub fn utc_timestamp(mut self, utc_timestamp: Box<dyn UTCTimestampGateway>) -> Self {
+        self.utc_timestamp = Some(utc_timestamp);
+        self
+    }
+
+    /// Sets the shared logger handle.
+    pub fn logger(mut self, logger: Arc<dyn Logger>) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    /// Validates and returns a built [`Gateways`] bundle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] of [`Kind::InvalidInput`] for the system audience when any of the
+    /// three gateways was not provided.
+    pub fn try_build(self) -> Result<Gateways, Error> {
+        Ok(Gateways {
+            identity: self.identity.ok_or_else(|| missing_gateway("identity"))?,
+            utc_timestamp: self
+                .utc_timestamp
+                .ok_or_else(|| missing_gateway("UTC timestamp"))?,
+            logger: self.logger.ok_or_else(|| missing_gateway("logger"))?,
+        })
+    }
+}
+
+fn missing_gateway(name: &str) -> Error {
+    Error::for_system(
+        Kind::InvalidInput,
+        format!("The {name} gateway is required, but was not provided."),
+    )
+}
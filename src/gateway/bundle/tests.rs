@@ -0,0 +1,160 @@
+//! Verifies the legacy gateway bundle.
+//!
+//! Bounded unit under test:
+//! - `Gateways`
+//!
+//! Public interfaces verified:
+//! - `Gateways::builder`
+//! - `GatewaysBuilder::try_build`
+//! - `Gateways::identity`
+//! - `Gateways::utc_timestamp`
+//! - `Gateways::logger`
+//!
+//! Logical paths covered:
+//! - each bundled gateway is reachable through its accessor
+//! - a cloned bundle calls the same gateways
+//! - a missing gateway is rejected
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+#![allow(deprecated)]
+
+use super::Gateways;
+use crate::error::{Audience, Error, Kind};
+use crate::gateway::identity::IdentityGateway;
+use crate::gateway::logger::Logger;
+use crate::gateway::utc_timestamp::UTCTimestampGateway;
+use crate::ulid::ULID;
+use crate::values::datetime::utc_timestamp::UTCTimestamp;
+use std::sync::{Arc, Mutex};
+use test_framework_oss::{is_ok, kernel_error_eq};
+
+#[derive(Clone)]
+struct StaticIdentityGateway {
+    identity: ULID,
+}
+
+impl IdentityGateway for StaticIdentityGateway {
+    fn generate(&self) -> Result<ULID, Error> {
+        Ok(self.identity)
+    }
+}
+
+#[derive(Clone)]
+struct StaticUTCTimestampGateway {
+    timestamp: UTCTimestamp,
+}
+
+impl UTCTimestampGateway for StaticUTCTimestampGateway {
+    fn now(&self) -> Result<UTCTimestamp, Error> {
+        Ok(self.timestamp)
+    }
+}
+
+#[derive(Default)]
+struct RecordingLogger {
+    entries: Mutex<Vec<String>>,
+}
+
+impl RecordingLogger {
+    fn record(&self, entry: String) {
+        match self.entries.lock() {
+            Ok(mut entries) => entries.push(entry),
+            Err(_) => panic!("expected logger entries lock"),
+        }
+    }
+
+    fn recorded_entries(&self) -> Vec<String> {
+        match self.entries.lock() {
+            Ok(entries) => entries.clone(),
+            Err(_) => panic!("expected logger entries lock"),
+        }
+    }
+}
+
+impl Logger for RecordingLogger {
+    fn error(&self, error: Error, _additional_context: Option<&str>) {
+        self.record(format!("error:{}", error.message));
+    }
+
+    fn warning(&self, warning: &str, _error: Option<Error>) {
+        self.record(format!("warning:{}", warning));
+    }
+
+    fn info(&self, info: &str, _error: Option<Error>) {
+        self.record(format!("info:{}", info));
+    }
+
+    fn debug(&self, debug: &str) {
+        self.record(format!("debug:{}", debug));
+    }
+}
+
+fn timestamp() -> UTCTimestamp {
+    is_ok!(UTCTimestamp::builder().use_ns(1_500_000u128).build())
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that each bundled gateway is called through the bundle, including
+/// after the bundle is cloned.
+#[test]
+fn bundle_calls_each_gateway_success() {
+    let identity = ULID::from_parts(1_700_000_000_000, 7);
+    let logger = Arc::new(RecordingLogger::default());
+
+    let gateways = is_ok!(
+        Gateways::builder()
+            .identity(Box::new(StaticIdentityGateway { identity }))
+            .utc_timestamp(Box::new(StaticUTCTimestampGateway {
+                timestamp: timestamp()
+            }))
+            .logger(logger.clone())
+            .try_build()
+    );
+    let cloned = gateways.clone();
+
+    assert_eq!(is_ok!(gateways.identity().generate()), identity);
+    assert_eq!(is_ok!(cloned.identity().generate()), identity);
+    assert_eq!(is_ok!(gateways.utc_timestamp().now()), timestamp());
+    assert_eq!(is_ok!(cloned.utc_timestamp().now()), timestamp());
+
+    gateways.logger().info("original", None);
+    cloned.logger().debug("cloned");
+    assert_eq!(
+        logger.recorded_entries(),
+        vec!["info:original".to_string(), "debug:cloned".to_string()]
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a bundle missing a gateway is rejected.
+#[test]
+fn missing_gateway_error() {
+    let missing_logger = Gateways::builder()
+        .identity(Box::new(StaticIdentityGateway {
+            identity: ULID::nil(),
+        }))
+        .utc_timestamp(Box::new(StaticUTCTimestampGateway {
+            timestamp: timestamp(),
+        }))
+        .try_build();
+    kernel_error_eq!(
+        missing_logger,
+        Kind::InvalidInput,
+        Audience::System,
+        "The logger gateway is required, but was not provided."
+    );
+
+    let missing_identity = Gateways::builder()
+        .logger(Arc::new(RecordingLogger::default()))
+        .try_build();
+    kernel_error_eq!(
+        missing_identity,
+        Kind::InvalidInput,
+        Audience::System,
+        "The identity gateway is required, but was not provided."
+    );
+}
@@ -7,6 +7,10 @@
 mod tests;
 
 use crate::error::Error;
+#[cfg(any(test, feature = "test-util"))]
+use crate::error::Kind;
+#[allow(deprecated)]
+use crate::gateway::utc_timestamp::UTCTimestampGateway;
 use crate::ulid::ULID;
 
 /// Defines the behavior for an implementation which provides a [ULID] which are unique identifier be used as identities for persistable entities.
@@ -52,3 +56,76 @@ impl Clone for Box<dyn IdentityGateway> {
         self.clone_box()
     }
 }
+
+/// Supplies the random bits of each [ULID] minted by [`DefaultIdentityGateway`].
+///
+/// Only the low 80 bits of each value are used. Implement it over the platform random number
+/// generator in production, and over a fixed or counting value in tests.
+pub trait IdentitySource: Send + Sync {
+    /// Returns fresh random bits for the next identity.
+    fn random_bits(&self) -> u128;
+}
+
+/// An [`IdentityGateway`] that mints each [ULID] from a clock reading and an [`IdentitySource`].
+///
+/// Every [`IdentityGateway::generate`] call reads the clock once, keeps its milliseconds as the
+/// timestamp, and fills the 80 random bits from the source.
+#[allow(deprecated)]
+#[derive(Clone)]
+pub struct DefaultIdentityGateway<S> {
+    /// The clock whose milliseconds become the timestamp.
+    clock: Box<dyn UTCTimestampGateway>,
+    /// The source of the random bits.
+    source: S,
+}
+
+#[allow(deprecated)]
+impl<S> DefaultIdentityGateway<S> {
+    /// Creates a gateway that reads `clock` and `source` on every call.
+    pub fn new(clock: Box<dyn UTCTimestampGateway>, source: S) -> Self {
+        DefaultIdentityGateway { clock, source }
+    }
+}
+
+#[allow(deprecated)]
+impl<S: IdentitySource + Clone + 'static> IdentityGateway for DefaultIdentityGateway<S> {
+    /// Mints a [ULID] for the current millisecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns the clock's error unchanged, or the [`ULID::try_from_parts`] error when the
+    /// clock's milliseconds do not fit in the 48-bit [ULID] timestamp.
+    fn generate(&self) -> Result<ULID, Error> {
+        let millis = self.clock.now()?.as_milli();
+        ULID::try_from_parts(millis, self.source.random_bits())
+    }
+}
+
+/// Generates `n` identities from `gateway` and fails on the first duplicate.
+///
+/// A quick sanity check for operators validating an [`IdentityGateway`] implementation. It is
+/// a self-test, not a proof: a gateway that only collides across processes or after `n` calls
+/// still passes.
+///
+/// # Errors
+///
+/// Returns the gateway's error unchanged, or a system-facing [`Kind::ProcessingFailure`] error
+/// naming the duplicate [ULID] and the call that returned it.
+#[cfg(any(test, feature = "test-util"))]
+#[allow(deprecated)]
+pub fn assert_no_collisions(gateway: &dyn IdentityGateway, n: usize) -> Result<(), Error> {
+    let mut seen = std::collections::HashSet::with_capacity(n);
+    for call in 1..=n {
+        let identity = gateway.generate()?;
+        if !seen.insert(identity) {
+            return Err(Error::for_system(
+                Kind::ProcessingFailure,
+                format!(
+                    "The identity gateway returned the duplicate ULID {} on call {} of {}.",
+                    identity, call, n
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
@@ -3,24 +3,36 @@
 //! Bounded unit under test:
 //! - `IdentityGateway`
 //! - `IdentityGatewayClone`
+//! - `DefaultIdentityGateway`
 //!
 //! Public interfaces verified:
 //! - `IdentityGateway::generate`
 //! - boxed trait-object cloning
+//! - `DefaultIdentityGateway::new` and `DefaultIdentityGateway::generate`
+//! - `assert_no_collisions`
 //!
 //! Logical paths covered:
 //! - cloneable implementations can be cloned as boxed trait objects
 //! - the legacy gateway contract returns the configured identity
+//! - the default gateway combines the clock's milliseconds with the source's low 80 bits
+//! - the default gateway passes clock errors through and rejects times past the ULID range
+//! - the collision self-test accepts unique identities and reports a gateway that repeats one
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
 #![allow(deprecated)]
 
-use crate::error::Error;
-use crate::gateway::identity::IdentityGateway;
+use crate::error::{Audience, Error, Kind};
+use crate::gateway::identity::{
+    DefaultIdentityGateway, IdentityGateway, IdentitySource, assert_no_collisions,
+};
+use crate::gateway::utc_timestamp::UTCTimestampGateway;
 use crate::ulid::ULID;
-use test_framework_oss::is_ok;
+use crate::values::datetime::utc_timestamp::UTCTimestamp;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use test_framework_oss::{is_error, is_ok, kernel_error_eq};
 
 #[derive(Clone)]
 struct StaticIdentityGateway {
@@ -59,3 +71,111 @@ fn clone_box_success() {
     let actual = is_ok!(cloned.generate());
     assert_eq!(actual, expected);
 }
+
+#[derive(Clone)]
+struct FixedClock {
+    result: Result<UTCTimestamp, Error>,
+}
+
+impl UTCTimestampGateway for FixedClock {
+    fn now(&self) -> Result<UTCTimestamp, Error> {
+        self.result.clone()
+    }
+}
+
+/// Returns the seed on the first call and one more on each later call.
+#[derive(Clone)]
+struct CountingSource {
+    seed: u128,
+    calls: Arc<AtomicU64>,
+}
+
+impl IdentitySource for CountingSource {
+    fn random_bits(&self) -> u128 {
+        self.seed + u128::from(self.calls.fetch_add(1, Ordering::SeqCst))
+    }
+}
+
+fn default_gateway(
+    result: Result<UTCTimestamp, Error>,
+    seed: u128,
+) -> DefaultIdentityGateway<CountingSource> {
+    DefaultIdentityGateway::new(
+        Box::new(FixedClock { result }),
+        CountingSource {
+            seed,
+            calls: Arc::new(AtomicU64::new(0)),
+        },
+    )
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the default gateway stamps the clock's milliseconds and the source's low 80
+/// bits, reading the source on every call, including through a boxed clone.
+#[test]
+fn default_generate_success() {
+    let now = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(1_700_000_000_123_456_789)
+            .build()
+    );
+    let gateway = default_gateway(Ok(now), 7);
+
+    let first = is_ok!(gateway.generate());
+    let second = is_ok!(gateway.generate());
+
+    assert_eq!(first.timestamp_ms(), 1_700_000_000_123);
+    assert_eq!(first.random(), 7);
+    assert_eq!(second.timestamp_ms(), 1_700_000_000_123);
+    assert_eq!(second.random(), 8);
+
+    let boxed: Box<dyn IdentityGateway> = Box::new(gateway);
+    let third = is_ok!(boxed.clone().generate());
+    assert_eq!(third.random(), 9);
+
+    let wide = default_gateway(Ok(now), u128::MAX);
+    let masked = is_ok!(wide.generate());
+    assert_eq!(masked.timestamp_ms(), 1_700_000_000_123);
+    assert_eq!(masked.random(), (1u128 << ULID::RAND_BITS) - 1);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that clock errors pass through unchanged and that a time past the largest ULID
+/// timestamp is rejected.
+#[test]
+fn default_generate_error() {
+    let clock_error = Error::for_system(Kind::GatewayError, "clock unavailable");
+    let gateway = default_gateway(Err(clock_error.clone()), 0);
+    assert_eq!(is_error!(gateway.generate()), clock_error);
+
+    let too_late = is_ok!(UTCTimestamp::builder().use_ms(1 << ULID::TIME_BITS).build());
+    kernel_error_eq!(
+        default_gateway(Ok(too_late), 0).generate(),
+        Kind::InvalidInput,
+        Audience::System,
+        "The timestamp of 281474976710656 ms is past the largest ULID timestamp."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the collision self-test passes a gateway that mints unique identities and
+/// reports a broken gateway that always returns the same identity.
+#[test]
+fn assert_no_collisions_success_and_error() {
+    let now = is_ok!(UTCTimestamp::builder().use_ms(1_700_000_000_000u64).build());
+    is_ok!(assert_no_collisions(&default_gateway(Ok(now), 0), 1_000));
+
+    let broken = StaticIdentityGateway {
+        identity: ULID::nil(),
+    };
+    is_ok!(assert_no_collisions(&broken, 1));
+    kernel_error_eq!(
+        assert_no_collisions(&broken, 1_000),
+        Kind::ProcessingFailure,
+        Audience::System,
+        "The identity gateway returned the duplicate ULID 00000000000000000000000000 on call 2 of 1000."
+    );
+}
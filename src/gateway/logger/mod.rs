@@ -7,6 +7,7 @@
 mod tests;
 
 use crate::error::Error;
+use std::sync::{Mutex, PoisonError};
 
 #[deprecated(
     note = "Use gateway::write_log_entry::WriteLogEntryGW, which implements the shared Gateway seam."
@@ -54,3 +55,197 @@ pub trait Logger: Sync + Send {
     /// Emits a debug-level log entry.
     fn debug(&self, debug: &str);
 }
+
+/// A [`Logger`] decorator that remembers the most recent error passed to [`Logger::error`].
+///
+/// Every call is forwarded unchanged to the wrapped logger, so the decorator can be dropped in
+/// wherever a [`Logger`] is expected. Health and diagnostic endpoints read the captured error with
+/// [`LastErrorLogger::last_error`]. Errors attached to warning or info entries are context for
+/// those entries and are not captured.
+pub struct LastErrorLogger<L> {
+    /// The logger every call is forwarded to.
+    inner: L,
+    /// The most recent error logged through [`Logger::error`].
+    last_error: Mutex<Option<Error>>,
+}
+
+impl<L> LastErrorLogger<L> {
+    /// Wraps `inner`, starting with no captured error.
+    pub fn new(inner: L) -> Self {
+        LastErrorLogger {
+            inner,
+            last_error: Mutex::new(None),
+        }
+    }
+
+    /// Returns the wrapped logger.
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Returns the most recent error logged through [`Logger::error`], or `None` before any error.
+    pub fn last_error(&self) -> Option<Error> {
+        self.last_error
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[allow(deprecated)]
+impl<L: Logger> Logger for LastErrorLogger<L> {
+    fn error(&self, error: Error, additional_context: Option<&str>) {
+        // A poisoned lock only means another caller panicked mid-store; the slot is still a whole
+        // `Option<Error>`, so recording the newest error is safe.
+        *self
+            .last_error
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(error.clone());
+        self.inner.error(error, additional_context);
+    }
+
+    fn warning(&self, warning: &str, error: Option<Error>) {
+        self.inner.warning(warning, error);
+    }
+
+    fn info(&self, info: &str, error: Option<Error>) {
+        self.inner.info(info, error);
+    }
+
+    fn debug(&self, debug: &str) {
+        self.inner.debug(debug);
+    }
+}
+
+/// A [`Logger`] that discards every entry.
+///
+/// Use it in tests, or as the default when a caller turns logging off, instead of writing an
+/// empty implementation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullLogger;
+
+#[allow(deprecated)]
+impl Logger for NullLogger {
+    fn error(&self, _error: Error, _additional_context: Option<&str>) {}
+
+    fn warning(&self, _warning: &str, _error: Option<Error>) {}
+
+    fn info(&self, _info: &str, _error: Option<Error>) {}
+
+    fn debug(&self, _debug: &str) {}
+}
+
+/// A [`Logger`] that forwards every call to each contained logger, in order.
+///
+/// Use it to send the same entries to several sinks, such as standard output and a remote
+/// collector. The [`Error`] passed to [`Logger::error`], [`Logger::warning`], and [`Logger::info`]
+/// is cloned for each sink, so every sink receives the same owned value.
+#[allow(deprecated)]
+#[derive(Default)]
+pub struct TeeLogger {
+    /// The sinks every call is forwarded to, in order.
+    loggers: Vec<Box<dyn Logger>>,
+}
+
+#[allow(deprecated)]
+impl TeeLogger {
+    /// Creates a logger that forwards to `loggers` in the given order.
+    pub fn new(loggers: Vec<Box<dyn Logger>>) -> Self {
+        TeeLogger { loggers }
+    }
+
+    /// Appends `logger`, which receives each call after the existing sinks.
+    pub fn push(&mut self, logger: Box<dyn Logger>) {
+        self.loggers.push(logger);
+    }
+}
+
+#[allow(deprecated)]
+impl Logger for TeeLogger {
+    fn error(&self, error: Error, additional_context: Option<&str>) {
+        for logger in &self.loggers {
+            logger.error(error.clone(), additional_context);
+        }
+    }
+
+    fn warning(&self, warning: &str, error: Option<Error>) {
+        for logger in &self.loggers {
+            logger.warning(warning, error.clone());
+        }
+    }
+
+    fn info(&self, info: &str, error: Option<Error>) {
+        for logger in &self.loggers {
+            logger.info(info, error.clone());
+        }
+    }
+
+    fn debug(&self, debug: &str) {
+        for logger in &self.loggers {
+            logger.debug(debug);
+        }
+    }
+}
+
+/// A [`Logger`] that forwards every call to the [`log`] crate facade.
+///
+/// Applications that already install a `log` backend can pass this adapter wherever a
+/// [`Logger`] is expected and receive kernel log entries alongside their own. Each method maps to
+/// the matching `log` level (`warning` maps to [`log::Level::Warn`]). An attached [`Error`] is
+/// rendered as its message followed by its context entries, such as
+/// `"boom [path=/tmp/a, attempt=2]"`.
+#[cfg(feature = "log")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogCrateLogger;
+
+#[cfg(feature = "log")]
+impl LogCrateLogger {
+    /// Creates the adapter. It holds no state; the installed `log` backend decides where entries go.
+    pub fn new() -> Self {
+        LogCrateLogger
+    }
+}
+
+/// Renders an error message followed by its context entries in bracketed `key=value` form.
+#[cfg(feature = "log")]
+fn describe_error(error: &Error) -> String {
+    if error.context().is_empty() {
+        return error.message().to_string();
+    }
+    let context = error
+        .context()
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} [{}]", error.message(), context)
+}
+
+#[cfg(feature = "log")]
+#[allow(deprecated)]
+impl Logger for LogCrateLogger {
+    fn error(&self, error: Error, additional_context: Option<&str>) {
+        match additional_context {
+            Some(context) => log::error!("{}: {}", describe_error(&error), context),
+            None => log::error!("{}", describe_error(&error)),
+        }
+    }
+
+    fn warning(&self, warning: &str, error: Option<Error>) {
+        match error {
+            Some(error) => log::warn!("{}: {}", warning, describe_error(&error)),
+            None => log::warn!("{}", warning),
+        }
+    }
+
+    fn info(&self, info: &str, error: Option<Error>) {
+        match error {
+            Some(error) => log::info!("{}: {}", info, describe_error(&error)),
+            None => log::info!("{}", info),
+        }
+    }
+
+    fn debug(&self, debug: &str) {
+        log::debug!("{}", debug);
+    }
+}
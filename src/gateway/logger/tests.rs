@@ -2,15 +2,28 @@
 //!
 //! Bounded unit under test:
 //! - `Logger`
+//! - `LastErrorLogger`
+//! - `NullLogger`
+//! - `TeeLogger`
 //!
 //! Public interfaces verified:
 //! - `Logger::error`
 //! - `Logger::warning`
 //! - `Logger::info`
 //! - `Logger::debug`
+//! - `LastErrorLogger::new`
+//! - `LastErrorLogger::last_error`
+//! - `NullLogger` as a `Logger`
+//! - `TeeLogger::new`
+//! - `TeeLogger::push`
+//! - `LogCrateLogger` (with the `log` feature)
 //!
 //! Logical paths covered:
 //! - each legacy log level forwards a message and optional error context
+//! - the last-error decorator forwards every call and captures only the newest error-level error
+//! - the null logger accepts every call without effect
+//! - the tee logger forwards every call to each sink in order, including sinks pushed later
+//! - the `log` adapter forwards each level with the error message, error context, and additional context
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
@@ -18,7 +31,7 @@
 #![allow(deprecated)]
 
 use crate::error::{Error, Kind};
-use crate::gateway::logger::Logger;
+use crate::gateway::logger::{LastErrorLogger, Logger, NullLogger, TeeLogger};
 use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Default)]
@@ -101,3 +114,239 @@ fn logger_methods_success() {
         ]
     );
 }
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the decorator has no captured error before any error is logged,
+/// even when warnings carry an error.
+#[test]
+fn last_error_none_before_error_success() {
+    let logger = LastErrorLogger::new(RecordingLogger::default());
+
+    logger.warning("warn", Some(Error::for_user(Kind::InvalidInput, "boom")));
+    logger.info("info", None);
+    logger.debug("debug");
+
+    assert_eq!(logger.last_error(), None);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the decorator captures the most recent error and forwards every
+/// call to the wrapped logger unchanged.
+#[test]
+fn last_error_tracks_most_recent_error_success() {
+    let logger = LastErrorLogger::new(RecordingLogger::default());
+    let first = Error::for_user(Kind::InvalidInput, "first");
+    let second = Error::for_system(Kind::GatewayError, "second");
+
+    logger.error(first.clone(), None);
+    assert_eq!(logger.last_error(), Some(first));
+
+    logger.error(second.clone(), Some("context"));
+    logger.info("info", None);
+    assert_eq!(logger.last_error(), Some(second));
+
+    assert_eq!(
+        logger.inner().recorded_entries(),
+        vec![
+            "error:first:".to_string(),
+            "error:second:context".to_string(),
+            "info:info:".to_string(),
+        ]
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the null logger accepts every legacy call and works wherever a `Logger` is
+/// expected, including behind the last-error decorator.
+#[test]
+fn null_logger_discards_entries_success() {
+    let logger = NullLogger;
+    let error = Error::for_user(Kind::InvalidInput, "boom");
+
+    logger.error(error.clone(), Some("context"));
+    logger.warning("warn", Some(error.clone()));
+    logger.info("info", None);
+    logger.debug("debug");
+
+    let boxed: Box<dyn Logger> = Box::new(NullLogger);
+    boxed.debug("debug");
+
+    let decorated = LastErrorLogger::new(NullLogger);
+    decorated.error(error.clone(), None);
+    assert_eq!(decorated.last_error(), Some(error));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the tee logger forwards each call, with the same error, to every sink in order,
+/// including a sink pushed after construction.
+#[test]
+fn tee_logger_fans_out_success() {
+    let first = RecordingLogger::default();
+    let second = RecordingLogger::default();
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut logger = TeeLogger::new(vec![
+        Box::new(OrderLogger::new("first", &order)),
+        Box::new(first.clone()),
+    ]);
+    logger.push(Box::new(second.clone()));
+    logger.push(Box::new(OrderLogger::new("last", &order)));
+    let error = Error::for_user(Kind::InvalidInput, "boom");
+
+    logger.error(error.clone(), Some("context"));
+    logger.warning("warn", Some(error));
+    logger.info("info", None);
+    logger.debug("debug");
+
+    let expected = vec![
+        "error:boom:context".to_string(),
+        "warning:warn:boom".to_string(),
+        "info:info:".to_string(),
+        "debug:debug".to_string(),
+    ];
+    assert_eq!(first.recorded_entries(), expected);
+    assert_eq!(second.recorded_entries(), expected);
+    match order.lock() {
+        Ok(order) => assert_eq!(
+            *order,
+            [
+                "first", "last", "first", "last", "first", "last", "first", "last"
+            ]
+        ),
+        Err(_) => panic!("expected order lock"),
+    }
+}
+
+/// Records only its own name for every call, so tests can observe the order sinks are called in.
+struct OrderLogger {
+    name: &'static str,
+    order: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl OrderLogger {
+    fn new(name: &'static str, order: &Arc<Mutex<Vec<&'static str>>>) -> Self {
+        OrderLogger {
+            name,
+            order: Arc::clone(order),
+        }
+    }
+
+    fn record(&self) {
+        match self.order.lock() {
+            Ok(mut order) => order.push(self.name),
+            Err(_) => panic!("expected order lock"),
+        }
+    }
+}
+
+impl Logger for OrderLogger {
+    fn error(&self, _error: Error, _additional_context: Option<&str>) {
+        self.record();
+    }
+
+    fn warning(&self, _warning: &str, _error: Option<Error>) {
+        self.record();
+    }
+
+    fn info(&self, _info: &str, _error: Option<Error>) {
+        self.record();
+    }
+
+    fn debug(&self, _debug: &str) {
+        self.record();
+    }
+}
+
+/// Records `log` facade entries per thread so parallel tests cannot see each other's records.
+#[cfg(feature = "log")]
+struct CapturingBackend {
+    records: Mutex<Vec<(std::thread::ThreadId, log::Level, String)>>,
+}
+
+#[cfg(feature = "log")]
+impl log::Log for CapturingBackend {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        match self.records.lock() {
+            Ok(mut records) => records.push((
+                std::thread::current().id(),
+                record.level(),
+                record.args().to_string(),
+            )),
+            Err(_) => panic!("expected backend records lock"),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(feature = "log")]
+static CAPTURING_BACKEND: CapturingBackend = CapturingBackend {
+    records: Mutex::new(Vec::new()),
+};
+
+/// Returns the records the capturing backend received from the current thread.
+#[cfg(feature = "log")]
+fn captured_on_this_thread() -> Vec<(log::Level, String)> {
+    let current = std::thread::current().id();
+    match CAPTURING_BACKEND.records.lock() {
+        Ok(records) => records
+            .iter()
+            .filter(|(thread, _, _)| *thread == current)
+            .map(|(_, level, message)| (*level, message.clone()))
+            .collect(),
+        Err(_) => panic!("expected backend records lock"),
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the `log` adapter forwards each legacy method at the matching
+/// level, rendering error context entries and additional context into the message.
+#[cfg(feature = "log")]
+#[test]
+fn log_crate_logger_forwards_levels_success() {
+    use crate::gateway::logger::LogCrateLogger;
+
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        if log::set_logger(&CAPTURING_BACKEND).is_err() {
+            panic!("expected to install the capturing backend");
+        }
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+
+    let logger = LogCrateLogger::new();
+    let error = Error::for_system(Kind::GatewayError, "boom")
+        .with_context("path", "/tmp/a")
+        .with_context("attempt", "2");
+
+    logger.error(error.clone(), Some("while syncing"));
+    logger.error(Error::for_user(Kind::InvalidInput, "plain"), None);
+    logger.warning("slow disk", Some(error));
+    logger.info("started", None);
+    logger.debug("tick");
+
+    assert_eq!(
+        captured_on_this_thread(),
+        vec![
+            (
+                log::Level::Error,
+                "boom [path=/tmp/a, attempt=2]: while syncing".to_string()
+            ),
+            (log::Level::Error, "plain".to_string()),
+            (
+                log::Level::Warn,
+                "slow disk: boom [path=/tmp/a, attempt=2]".to_string()
+            ),
+            (log::Level::Info, "started".to_string()),
+            (log::Level::Debug, "tick".to_string()),
+        ]
+    );
+}
@@ -5,6 +5,7 @@
 //! gateway boundary. Domain-specific `*GW` traits should stay as thin marker
 //! supertraits over these shared roles.
 
+pub mod bundle;
 pub mod current_utc_timestamp;
 pub mod directory_list;
 pub mod file_data;
@@ -13,6 +14,7 @@ pub mod identity;
 pub mod logger;
 pub mod new_identity;
 pub mod retrieve_directory_path;
+pub mod retry;
 pub mod write_log_entry;
 
 #[cfg(test)]
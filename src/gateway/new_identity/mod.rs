@@ -2,11 +2,13 @@
 //!
 //! This module provides the shared kernel seam for generating a new identity,
 //! plus the sync and async marker traits that bind the shared gateway role to
-//! that capability.
+//! that capability, and [`now_marker`] for deterministic per-millisecond markers.
 
 #[cfg(test)]
 mod tests;
 
+use crate::error::Error;
+use crate::gateway::current_utc_timestamp::CurrentUTCTimestampGW;
 use crate::gateway::{AsyncVoidGateway, VoidGateway};
 use crate::ulid::ULID;
 
@@ -15,3 +17,20 @@ pub trait NewIdentityGW: VoidGateway<Response = ULID> {}
 
 /// Defines the asynchronous domain seam for generating a new identity.
 pub trait AsyncNewIdentityGW: AsyncVoidGateway<Response = ULID> {}
+
+/// Returns a deterministic [`ULID`] marker for the current millisecond of `clock`.
+///
+/// The marker carries the clock's milliseconds with every random bit cleared, so the same
+/// millisecond always yields the same marker and it sorts before every identity minted in that
+/// millisecond. Use it for watermark records rather than as an entity identity.
+///
+/// # Errors
+///
+/// Returns the clock's error unchanged, or the [`ULID::try_from_parts`] error when the clock's
+/// milliseconds do not fit in the 48-bit [`ULID`] timestamp.
+pub fn now_marker<Clock>(clock: &Clock) -> Result<ULID, Error>
+where
+    Clock: CurrentUTCTimestampGW + ?Sized,
+{
+    ULID::try_from_parts(clock.execute()?.as_milli(), 0)
+}
@@ -7,22 +7,27 @@
 //! Public interfaces verified:
 //! - `VoidGateway::execute(&gateway as &dyn NewIdentityGW)`
 //! - `AsyncVoidGateway::execute(&gateway as &dyn AsyncNewIdentityGW)`
+//! - `now_marker`
 //!
 //! Logical paths covered:
 //! - successful execution returns a bounded `ULID`
 //! - boxed marker-seam execution returns a bounded `ULID`
 //! - asynchronous marker-seam execution returns a bounded `ULID`
+//! - a frozen clock yields a marker with its milliseconds and zero randomness
+//! - a clock past the ULID timestamp range is rejected
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
-use crate::error::Error;
-use crate::gateway::new_identity::{AsyncNewIdentityGW, NewIdentityGW};
+use crate::error::{Audience, Error, Kind};
+use crate::gateway::current_utc_timestamp::CurrentUTCTimestampGW;
+use crate::gateway::new_identity::{AsyncNewIdentityGW, NewIdentityGW, now_marker};
 use crate::gateway::{AsyncVoidGateway, VoidGateway};
 use crate::response::ResponseFuture;
 use crate::ulid::ULID;
+use crate::values::datetime::utc_timestamp::UTCTimestamp;
 use std::task::{Context, Poll};
-use test_framework_oss::is_ok;
+use test_framework_oss::{is_ok, kernel_error_eq};
 
 struct StaticNewIdentityGateway {
     identity: ULID,
@@ -54,6 +59,20 @@ impl AsyncVoidGateway for AsyncStaticNewIdentityGateway {
 
 impl AsyncNewIdentityGW for AsyncStaticNewIdentityGateway {}
 
+struct FrozenClock {
+    millis: u64,
+}
+
+impl VoidGateway for FrozenClock {
+    type Response = UTCTimestamp;
+
+    fn execute(&self) -> Result<Self::Response, Error> {
+        UTCTimestamp::builder().use_ms(self.millis).build()
+    }
+}
+
+impl CurrentUTCTimestampGW for FrozenClock {}
+
 /// Requirement validation: No requirement validation point is currently supplied.
 ///
 /// Verifies that executing the new identity gateway through the `NewIdentityGW`
@@ -103,6 +122,41 @@ fn async_execute_returns_new_identity_success() {
     assert_eq!(expected, actual);
 }
 
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a frozen clock yields a reproducible marker carrying the clock's milliseconds
+/// with a zero random portion.
+#[test]
+fn now_marker_frozen_clock_success() {
+    let clock = FrozenClock {
+        millis: 1_739_450_096_789,
+    };
+
+    let marker = is_ok!(now_marker(&clock));
+
+    assert_eq!(marker.value() >> ULID::RAND_BITS, 1_739_450_096_789);
+    assert_eq!(marker.random(), 0);
+    assert_eq!(
+        marker,
+        is_ok!(now_marker(&clock as &dyn CurrentUTCTimestampGW))
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a clock past the 48-bit ULID timestamp range is rejected instead of wrapping.
+#[test]
+fn now_marker_past_ulid_range_error() {
+    let clock = FrozenClock { millis: 1 << 48 };
+
+    kernel_error_eq!(
+        now_marker(&clock),
+        Kind::InvalidInput,
+        Audience::System,
+        "The timestamp of 281474976710656 ms is past the largest ULID timestamp."
+    );
+}
+
 fn try_run_ready<Response>(mut future: ResponseFuture<'_, Response>) -> Result<Response, Error> {
     let mut context = Context::from_waker(std::task::Waker::noop());
 
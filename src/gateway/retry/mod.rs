@@ -0,0 +1,55 @@
+//! Bounded retry helper for gateway calls.
+//!
+//! Wrap a gateway call with [`retry`] when its failures may be transient. Only
+//! errors reporting [`Error::is_retryable`] are retried; every other error is
+//! returned immediately.
+
+#[cfg(test)]
+mod tests;
+
+use crate::error::{Error, Kind};
+
+/// Calls `f` up to `attempts` times, retrying while it returns a retryable [`Error`].
+///
+/// The first success is returned. A non-retryable error is returned without further attempts,
+/// and the last error is returned once every attempt has failed. Attempts run back to back; this
+/// helper does not sleep between them.
+///
+/// # Errors
+///
+/// Returns an [`Error`] of [`Kind::InvalidInput`] for the system audience when `attempts` is `0`,
+/// without calling `f`.
+///
+/// # Example
+/// ```
+/// use kernel_oss::error::{Error, Kind};
+/// use kernel_oss::gateway::retry::retry;
+///
+/// let mut calls = 0;
+/// let result = retry(3, || {
+///     calls += 1;
+///     if calls < 2 {
+///         Err(Error::for_system(Kind::GatewayError, "timeout"))
+///     } else {
+///         Ok(calls)
+///     }
+/// });
+///
+/// assert_eq!(result, Ok(2));
+/// ```
+pub fn retry<T>(attempts: u32, mut f: impl FnMut() -> Result<T, Error>) -> Result<T, Error> {
+    if attempts == 0 {
+        return Err(Error::for_system(
+            Kind::InvalidInput,
+            "At least one attempt is required to retry a gateway call.",
+        ));
+    }
+
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(error) if error.is_retryable() && attempt < attempts => attempt += 1,
+            result => return result,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Verifies the bounded gateway retry helper.
+//!
+//! Bounded unit under test:
+//! - `retry`
+//!
+//! Public interfaces verified:
+//! - `retry`
+//!
+//! Logical paths covered:
+//! - a call that fails twice with a retryable error then succeeds
+//! - a call that always fails returns the last error after every attempt
+//! - a non-retryable error is returned without retrying
+//! - zero attempts are rejected without calling the gateway
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::retry;
+use crate::error::{Audience, Error, Kind};
+use test_framework_oss::{is_ok, kernel_error_eq};
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that retryable failures are retried until the call succeeds.
+#[test]
+fn fails_twice_then_succeeds_success() {
+    let mut calls = 0;
+
+    let result = retry(5, || {
+        calls += 1;
+        if calls <= 2 {
+            Err(Error::for_system(Kind::GatewayError, "timeout"))
+        } else {
+            Ok("generated")
+        }
+    });
+
+    assert_eq!(is_ok!(result), "generated");
+    assert_eq!(calls, 3);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the last error is returned once every attempt has failed.
+#[test]
+fn always_fails_error() {
+    let mut calls = 0;
+
+    let result: Result<(), Error> = retry(3, || {
+        calls += 1;
+        Err(Error::for_system(
+            Kind::GatewayError,
+            format!("timeout {calls}"),
+        ))
+    });
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System, "timeout 3");
+    assert_eq!(calls, 3);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a non-retryable error is returned after a single attempt.
+#[test]
+fn non_retryable_error() {
+    let mut calls = 0;
+
+    let result: Result<(), Error> = retry(3, || {
+        calls += 1;
+        Err(Error::for_user(Kind::InvalidInput, "bad input"))
+    });
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "bad input");
+    assert_eq!(calls, 1);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that zero attempts are rejected without calling the gateway.
+#[test]
+fn zero_attempts_error() {
+    let mut calls = 0;
+
+    let result = retry(0, || {
+        calls += 1;
+        Ok(())
+    });
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "At least one attempt is required to retry a gateway call."
+    );
+    assert_eq!(calls, 0);
+}
@@ -8,6 +8,8 @@ mod tests;
 
 use crate::error::Error;
 use crate::values::datetime::utc_timestamp::UTCTimestamp;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 #[deprecated(
     note = "Use gateway::current_utc_timestamp::CurrentUTCTimestampGW, which implements the shared Gateway seam."
@@ -41,3 +43,60 @@ impl Clone for Box<dyn UTCTimestampGateway> {
         self.clone_box()
     }
 }
+
+/// A [`UTCTimestampGateway`] decorator that reuses the inner gateway's last reading for a window.
+///
+/// Use it when a request reads the clock many times but a coarser resolution is enough. A call
+/// within `window` of the last refresh returns the cached timestamp; a later call asks the inner
+/// gateway again. Staleness is measured with the monotonic [`Instant`], so wall-clock jumps do
+/// not extend or cut short the window. Inner errors are returned unchanged and are not cached.
+///
+/// Clones share the cache, so a clock cloned into several handlers still reads the inner
+/// gateway at most once per window.
+#[derive(Clone)]
+pub struct CachedClock<G> {
+    /// The gateway read when the cache is empty or stale.
+    inner: G,
+    /// How long a reading stays fresh.
+    window: Duration,
+    /// The last reading and when it was taken.
+    cached: Arc<Mutex<Option<(Instant, UTCTimestamp)>>>,
+}
+
+impl<G> CachedClock<G> {
+    /// Wraps `inner`, caching each reading for `window`. A zero window disables caching.
+    pub fn new(inner: G, window: Duration) -> Self {
+        CachedClock {
+            inner,
+            window,
+            cached: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the wrapped gateway.
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    /// Returns how long a reading stays fresh.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+}
+
+#[allow(deprecated)]
+impl<G: UTCTimestampGateway + Clone + 'static> UTCTimestampGateway for CachedClock<G> {
+    fn now(&self) -> Result<UTCTimestamp, Error> {
+        // Holding the lock across the inner read keeps concurrent callers from refreshing twice.
+        // A poisoned lock still holds a whole reading, so it is safe to keep using.
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((taken, timestamp)) = *cached
+            && taken.elapsed() < self.window
+        {
+            return Ok(timestamp);
+        }
+        let timestamp = self.inner.now()?;
+        *cached = Some((Instant::now(), timestamp));
+        Ok(timestamp)
+    }
+}
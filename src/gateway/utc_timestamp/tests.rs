@@ -3,24 +3,31 @@
 //! Bounded unit under test:
 //! - `UTCTimestampGateway`
 //! - `UTCTimestampGatewayClone`
+//! - `CachedClock`
 //!
 //! Public interfaces verified:
 //! - `UTCTimestampGateway::now`
 //! - boxed trait-object cloning
+//! - `CachedClock::new` and `CachedClock::now`
 //!
 //! Logical paths covered:
 //! - cloneable implementations can be cloned as boxed trait objects
 //! - the legacy gateway contract returns the configured timestamp
+//! - the cached clock reuses a reading within its window, shares it across clones, and refreshes once stale
+//! - the cached clock does not cache inner errors
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
 #![allow(deprecated)]
 
-use crate::error::Error;
-use crate::gateway::utc_timestamp::UTCTimestampGateway;
+use crate::error::{Error, Kind};
+use crate::gateway::utc_timestamp::{CachedClock, UTCTimestampGateway};
 use crate::values::datetime::utc_timestamp::UTCTimestamp;
-use test_framework_oss::is_ok;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use test_framework_oss::{is_error, is_ok};
 
 #[derive(Clone)]
 struct StaticUTCTimestampGateway {
@@ -63,3 +70,90 @@ fn clone_box_success() {
     let actual = is_ok!(cloned.now());
     assert_eq!(actual, expected);
 }
+
+/// Returns a later timestamp on every call and counts the calls, so tests can see cache hits.
+#[derive(Clone, Default)]
+struct CountingUTCTimestampGateway {
+    calls: Arc<AtomicU64>,
+}
+
+impl CountingUTCTimestampGateway {
+    fn calls(&self) -> u64 {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+impl UTCTimestampGateway for CountingUTCTimestampGateway {
+    fn now(&self) -> Result<UTCTimestamp, Error> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        UTCTimestamp::builder()
+            .use_ms(1_700_000_000_000 + call)
+            .build()
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that calls within the window return the cached reading, including through a clone,
+/// without asking the inner gateway again.
+#[test]
+fn cached_clock_within_window_success() {
+    let inner = CountingUTCTimestampGateway::default();
+    let clock = CachedClock::new(inner.clone(), Duration::from_secs(3_600));
+
+    let first = is_ok!(clock.now());
+    let second = is_ok!(clock.now());
+    let from_clone = is_ok!(clock.clone().now());
+
+    assert_eq!(first.as_milli(), 1_700_000_000_001);
+    assert_eq!(second, first);
+    assert_eq!(from_clone, first);
+    assert_eq!(inner.calls(), 1);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a call after the window has passed refreshes the reading from the inner gateway.
+#[test]
+fn cached_clock_after_window_success() {
+    let inner = CountingUTCTimestampGateway::default();
+    let clock = CachedClock::new(inner.clone(), Duration::from_millis(1));
+
+    let first = is_ok!(clock.now());
+    std::thread::sleep(Duration::from_millis(5));
+    let refreshed = is_ok!(clock.now());
+
+    assert!(refreshed > first);
+    assert_eq!(inner.calls(), 2);
+
+    let uncached = CachedClock::new(inner.clone(), Duration::ZERO);
+    is_ok!(uncached.now());
+    is_ok!(uncached.now());
+    assert_eq!(inner.calls(), 4);
+}
+
+#[derive(Clone)]
+struct FailingUTCTimestampGateway;
+
+impl UTCTimestampGateway for FailingUTCTimestampGateway {
+    fn now(&self) -> Result<UTCTimestamp, Error> {
+        Err(Error::for_system(Kind::GatewayError, "clock unavailable"))
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an inner error is returned unchanged on every call rather than cached.
+#[test]
+fn cached_clock_inner_error() {
+    let clock = CachedClock::new(FailingUTCTimestampGateway, Duration::from_secs(3_600));
+
+    assert_eq!(
+        is_error!(clock.now()),
+        Error::for_system(Kind::GatewayError, "clock unavailable")
+    );
+    assert_eq!(
+        is_error!(clock.now()),
+        Error::for_system(Kind::GatewayError, "clock unavailable")
+    );
+}
@@ -14,13 +14,39 @@
 //! - the checked examples under `examples/`
 //!
 //! The catalog is curated guidance; rustdoc remains the generated API reference.
+//!
+//! # Features
+//!
+//! - `std` (default): the full kernel. Implies `alloc`.
+//! - `alloc`: the allocating [`ulid`] helpers, such as `ULID::to_string`.
+//! - `serde`: serialization support for selected values. Implies `std`.
+//! - `log`: `gateway::logger::LogCrateLogger`, which forwards legacy `Logger` calls to the `log`
+//!   crate facade. Implies `std`.
+//! - `test-util`: `gateway::identity::assert_no_collisions`, a self-test that checks an
+//!   `IdentityGateway` does not return duplicate identities. Implies `std`.
+//!
+//! Without `std` the crate is `#![no_std]` and only exposes [`ulid`], so identifiers can be
+//! parsed, compared, and encoded into fixed buffers on targets without an allocator.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "std")]
 pub mod algorithms;
+#[cfg(feature = "std")]
 pub mod core;
+#[cfg(feature = "std")]
 pub mod entity;
+#[cfg(feature = "std")]
 pub mod error;
+#[cfg(feature = "std")]
 pub mod gateway;
+#[cfg(feature = "std")]
 pub mod response;
 pub mod ulid;
+#[cfg(feature = "std")]
 pub mod usecase;
+#[cfg(feature = "std")]
 pub mod values;
@@ -2,6 +2,9 @@
 
 use core::fmt;
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
 /// Length of a string-encoded [`ULID`](crate::ulid::ULID).
 pub const ULID_LEN: usize = 26;
 
@@ -31,7 +34,7 @@ pub enum EncodeError {
     BufferTooSmall,
 }
 
-impl std::error::Error for EncodeError {}
+impl core::error::Error for EncodeError {}
 
 impl fmt::Display for EncodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
@@ -53,6 +56,7 @@ pub fn encode_to_array(mut value: u128, buffer: &mut [u8; ULID_LEN]) {
 }
 
 /// Encode a `u128` value to a Crockford Base32 string.
+#[cfg(feature = "alloc")]
 pub fn encode(value: u128) -> String {
     let mut buffer: [u8; ULID_LEN] = [0; ULID_LEN];
 
@@ -70,7 +74,7 @@ pub enum DecodeError {
     InvalidChar,
 }
 
-impl std::error::Error for DecodeError {}
+impl core::error::Error for DecodeError {}
 
 impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
@@ -82,6 +86,31 @@ impl fmt::Display for DecodeError {
     }
 }
 
+/// Check that `encoded` is [`ULID_LEN`] Crockford Base32 characters without decoding it.
+///
+/// Returns the same error [`decode`] would for the input.
+pub const fn validate(encoded: &str) -> Result<(), DecodeError> {
+    if encoded.len() != ULID_LEN {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let bytes = encoded.as_bytes();
+    let mut i = 0;
+    while i < ULID_LEN {
+        if LOOKUP[bytes[i] as usize] == NO_VALUE {
+            return Err(DecodeError::InvalidChar);
+        }
+        i += 1;
+    }
+
+    Ok(())
+}
+
+/// Returns `true` when [`validate`] accepts `encoded`, for quick input filtering.
+pub const fn is_valid(encoded: &str) -> bool {
+    validate(encoded).is_ok()
+}
+
 /// Decode a Crockford Base32 string into a `u128` value.
 pub const fn decode(encoded: &str) -> Result<u128, DecodeError> {
     if encoded.len() != ULID_LEN {
@@ -106,3 +135,154 @@ pub const fn decode(encoded: &str) -> Result<u128, DecodeError> {
 
     Ok(value)
 }
+
+/// Decode a Crockford Base32 string, ignoring ASCII whitespace and `-` grouping separators.
+///
+/// Use this for values copied from logs or user interfaces, such as ` 01D3-9ZY0-6FGS-CTVN-4T2V-9PKH-FZ `.
+/// The remaining characters must still be exactly [`ULID_LEN`] long; [`decode`] stays strict.
+pub const fn decode_lenient(encoded: &str) -> Result<u128, DecodeError> {
+    let mut cleaned = [0u8; ULID_LEN];
+    let mut len = 0;
+
+    let bytes = encoded.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if !byte.is_ascii_whitespace() && byte != b'-' {
+            if len == ULID_LEN {
+                return Err(DecodeError::InvalidLength);
+            }
+            cleaned[len] = byte;
+            len += 1;
+        }
+        i += 1;
+    }
+    if len != ULID_LEN {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    decode_normalized(&cleaned)
+}
+
+/// Runs [`decode`] on input that a lenient decoder has normalized into a fixed buffer.
+///
+/// Normalizing only removes or replaces ASCII bytes, so the buffer is still UTF-8.
+const fn decode_normalized(normalized: &[u8; ULID_LEN]) -> Result<u128, DecodeError> {
+    match core::str::from_utf8(normalized) {
+        Ok(encoded) => decode(encoded),
+        Err(_) => Err(DecodeError::InvalidChar),
+    }
+}
+
+/// Decode a Crockford Base32 string, reading `I`/`L` as `1` and `O` as `0` in either case.
+///
+/// The Crockford specification accepts these aliases so hand-typed values still decode.
+/// Use this only for human input; [`decode`] stays strict and rejects them as
+/// [`DecodeError::InvalidChar`].
+pub const fn decode_crockford_aliases(encoded: &str) -> Result<u128, DecodeError> {
+    if encoded.len() != ULID_LEN {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut normalized = [0u8; ULID_LEN];
+
+    let bytes = encoded.as_bytes();
+    let mut i = 0;
+    while i < ULID_LEN {
+        normalized[i] = match bytes[i] {
+            b'I' | b'i' | b'L' | b'l' => b'1',
+            b'O' | b'o' => b'0',
+            other => other,
+        };
+        i += 1;
+    }
+
+    decode_normalized(&normalized)
+}
+
+/// An error that can occur when encoding or decoding with a custom alphabet
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum AlphabetError {
+    /// The alphabet repeats a byte or contains a non-ASCII byte
+    InvalidAlphabet,
+    /// The string could not be decoded with the alphabet
+    Decode(DecodeError),
+}
+
+impl core::error::Error for AlphabetError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            AlphabetError::InvalidAlphabet => None,
+            AlphabetError::Decode(error) => Some(error),
+        }
+    }
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            AlphabetError::InvalidAlphabet => write!(f, "invalid alphabet"),
+            AlphabetError::Decode(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<DecodeError> for AlphabetError {
+    fn from(error: DecodeError) -> Self {
+        AlphabetError::Decode(error)
+    }
+}
+
+/// Encode a `u128` value with a custom 32-symbol `alphabet`, such as for branded identifiers.
+///
+/// The output can only be read back with [`decode_with_alphabet`] and the same alphabet.
+/// Other systems, and [`decode`], will reject it or decode a different value, so keep
+/// [`encode`] for identifiers that leave the product.
+///
+/// Returns [`AlphabetError::InvalidAlphabet`] unless `alphabet` holds 32 distinct ASCII bytes.
+#[cfg(feature = "alloc")]
+pub fn encode_with_alphabet(value: u128, alphabet: &[u8; 32]) -> Result<String, AlphabetError> {
+    if alphabet_lookup(alphabet).is_none() {
+        return Err(AlphabetError::InvalidAlphabet);
+    }
+
+    Ok((0..ULID_LEN)
+        .rev()
+        .map(|index| char::from(alphabet[((value >> (index * 5)) & 0x1f) as usize]))
+        .collect())
+}
+
+/// Decode a string produced by [`encode_with_alphabet`] with the same `alphabet`.
+///
+/// Symbols are matched exactly, so unlike [`decode`] lowercase input is not accepted for an
+/// uppercase alphabet. Returns [`AlphabetError::InvalidAlphabet`] unless `alphabet` holds 32
+/// distinct ASCII bytes, or [`AlphabetError::Decode`] when `encoded` is not [`ULID_LEN`]
+/// symbols of the alphabet.
+pub fn decode_with_alphabet(encoded: &str, alphabet: &[u8; 32]) -> Result<u128, AlphabetError> {
+    let lookup = alphabet_lookup(alphabet).ok_or(AlphabetError::InvalidAlphabet)?;
+    if encoded.len() != ULID_LEN {
+        return Err(DecodeError::InvalidLength.into());
+    }
+
+    let mut canonical = [0u8; ULID_LEN];
+    for (slot, byte) in canonical.iter_mut().zip(encoded.bytes()) {
+        *slot = match lookup[byte as usize] {
+            NO_VALUE => return Err(DecodeError::InvalidChar.into()),
+            symbol => ALPHABET[symbol as usize],
+        };
+    }
+
+    Ok(decode_normalized(&canonical)?)
+}
+
+/// Builds the reverse lookup for `alphabet`, or `None` if it repeats a byte or is not ASCII.
+fn alphabet_lookup(alphabet: &[u8; 32]) -> Option<[u8; 256]> {
+    let mut lookup = [NO_VALUE; 256];
+    for (index, &byte) in alphabet.iter().enumerate() {
+        if !byte.is_ascii() || lookup[byte as usize] != NO_VALUE {
+            return None;
+        }
+        lookup[byte as usize] = index as u8;
+    }
+    Some(lookup)
+}
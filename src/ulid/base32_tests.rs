@@ -3,6 +3,12 @@
 //! Bounded unit under test:
 //! - `encode`
 //! - `decode`
+//! - `decode_lenient`
+//! - `decode_crockford_aliases`
+//! - `validate`
+//! - `is_valid`
+//! - `encode_with_alphabet`
+//! - `decode_with_alphabet`
 //! - `LOOKUP`
 //! - `ALPHABET`
 //! - `ULID_LEN`
@@ -12,20 +18,33 @@
 //! - valid ULID byte encoding
 //! - valid ULID string decoding
 //! - invalid length and invalid character handling
+//! - lenient decoding of grouped and padded input
+//! - Crockford alias decoding of hand-typed input
+//! - shape validation without decoding
+//! - custom alphabet encoding and decoding
 //!
 //! Logical paths covered:
 //! - valid uppercase and lowercase encoding/decoding round-trips
 //! - lookup table entries cover uppercase and lowercase alphabet values
 //! - empty and non-exact-length inputs fail validation
 //! - invalid characters fail validation
+//! - lenient decoding ignores hyphens and whitespace but still enforces length and alphabet
+//! - alias decoding reads I/L as 1 and O as 0 in either case while strict decoding rejects them
+//! - shape validation agrees with decoding for valid, wrong-length, and bad-character input
+//! - custom alphabets round-trip, wrong-length or foreign-symbol input is a wrapped decode error,
+//!   and non-distinct or non-ASCII alphabets are rejected
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
 use crate::ulid::base32::ALPHABET;
+use crate::ulid::base32::AlphabetError;
 use crate::ulid::base32::DecodeError;
 use crate::ulid::base32::ULID_LEN;
-use crate::ulid::base32::{decode, encode};
+use crate::ulid::base32::{
+    decode, decode_crockford_aliases, decode_lenient, decode_with_alphabet, encode,
+    encode_with_alphabet, is_valid, validate,
+};
 use test_framework_oss::is_ok;
 
 /// Requirement validation: No requirement validation point is currently supplied.
@@ -112,3 +131,183 @@ fn chars_success() {
         Err(DecodeError::InvalidChar)
     );
 }
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that lenient decoding ignores grouping hyphens and surrounding whitespace while the
+/// strict decoder still rejects them.
+#[test]
+fn decode_lenient_success() {
+    let expected = is_ok!(decode("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert_eq!(
+        decode_lenient("01D3-9ZY0-6FGS-CTVN-4T2V-9PKH-FZ"),
+        Ok(expected)
+    );
+    assert_eq!(
+        decode_lenient("  01d39zy06fgsctvn4t2v9pkhfz\n"),
+        Ok(expected)
+    );
+    assert_eq!(decode_lenient("01D39ZY06FGSCTVN4T2V9PKHFZ"), Ok(expected));
+    assert_eq!(
+        decode("01D3-9ZY0-6FGS-CTVN-4T2V-9PKH-FZ"),
+        Err(DecodeError::InvalidLength)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that lenient decoding still enforces the length and alphabet of the cleaned input.
+#[test]
+fn decode_lenient_error() {
+    assert_eq!(
+        decode_lenient("01D3-9ZY0-6FGS-CTVN-4T2V-9PKH-F"),
+        Err(DecodeError::InvalidLength)
+    );
+    assert_eq!(
+        decode_lenient("01D39ZY06FGSCTVN4T2V9PKHFZ0"),
+        Err(DecodeError::InvalidLength)
+    );
+    assert_eq!(decode_lenient(" - "), Err(DecodeError::InvalidLength));
+    assert_eq!(
+        decode_lenient("01D3_9ZY06FGSCTVN4T2V9PKHF"),
+        Err(DecodeError::InvalidChar)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that shape validation accepts valid input and reports the same error as decoding
+/// for wrong-length and bad-character input.
+#[test]
+fn validate_matches_decode_success() {
+    let inputs = [
+        "01D39ZY06FGSCTVN4T2V9PKHFZ",
+        "01d39zy06fgsctvn4t2v9pkhfz",
+        "",
+        "01D39ZY06FGSCTVN4T2V9PKHF",
+        "01D39ZY06FGSCTVN4T2V9PKHFZ0",
+        "01D39ZY06FGSCTVN4T2V9PKHFU",
+        "01D39ZY06FGSCTVN4T2V9PKHF!",
+    ];
+
+    for input in inputs {
+        assert_eq!(
+            validate(input),
+            decode(input).map(|_| ()),
+            "input {input:?}"
+        );
+        assert_eq!(is_valid(input), decode(input).is_ok(), "input {input:?}");
+    }
+    assert!(is_valid("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+    assert_eq!(
+        validate("01D39ZY06FGSCTVN4T2V9PKHF"),
+        Err(DecodeError::InvalidLength)
+    );
+    assert_eq!(
+        validate("01D39ZY06FGSCTVN4T2V9PKHFU"),
+        Err(DecodeError::InvalidChar)
+    );
+}
+
+const BRANDED: &[u8; 32] = b"abcdefghijkmnpqrstuvwxyz23456789";
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that values round-trip through a custom alphabet and that the standard alphabet
+/// matches the default codec.
+#[test]
+fn custom_alphabet_round_trip_success() {
+    for value in [
+        0u128,
+        1,
+        0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f,
+        u128::MAX,
+    ] {
+        let encoded = is_ok!(encode_with_alphabet(value, BRANDED));
+        assert_eq!(encoded.len(), ULID_LEN);
+        assert!(encoded.bytes().all(|byte| BRANDED.contains(&byte)));
+        assert_eq!(decode_with_alphabet(&encoded, BRANDED), Ok(value));
+
+        let standard = is_ok!(encode_with_alphabet(value, ALPHABET));
+        assert_eq!(standard, encode(value));
+    }
+
+    assert_eq!(
+        decode_with_alphabet("AAAAAAAAAAAAAAAAAAAAAAAAAA", BRANDED),
+        Err(AlphabetError::Decode(DecodeError::InvalidChar))
+    );
+    assert_eq!(
+        decode_with_alphabet("aaaa", BRANDED),
+        Err(AlphabetError::Decode(DecodeError::InvalidLength))
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that alphabets with repeated or non-ASCII bytes are rejected.
+#[test]
+fn custom_alphabet_not_distinct_error() {
+    let repeated = b"abcdefghijkmnpqrstuvwxyz2345678a";
+    let mut non_ascii = *BRANDED;
+    non_ascii[0] = 0xE9;
+
+    assert_eq!(
+        encode_with_alphabet(1, repeated),
+        Err(AlphabetError::InvalidAlphabet)
+    );
+    assert_eq!(
+        decode_with_alphabet("aaaaaaaaaaaaaaaaaaaaaaaaaa", repeated),
+        Err(AlphabetError::InvalidAlphabet)
+    );
+    assert_eq!(
+        encode_with_alphabet(1, &non_ascii),
+        Err(AlphabetError::InvalidAlphabet)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that alias decoding reads `I`/`L` as `1` and `O` as `0` in either case, matching the
+/// canonical value, while the strict decoder rejects the aliases.
+#[test]
+fn decode_crockford_aliases_success() {
+    let expected = is_ok!(decode("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert_eq!(
+        decode_crockford_aliases("OID39ZY06FGSCTVN4T2V9PKHFZ"),
+        Ok(expected)
+    );
+    assert_eq!(
+        decode_crockford_aliases("oLD39ZYO6FGSCTVN4T2V9PKHFZ"),
+        Ok(expected)
+    );
+    assert_eq!(
+        decode_crockford_aliases("0lD39ZY06FGSCTVN4T2V9PKHFZ"),
+        Ok(expected)
+    );
+    assert_eq!(
+        decode_crockford_aliases("0iD39ZY06FGSCTVN4T2V9PKHFZ"),
+        Ok(expected)
+    );
+    assert_eq!(
+        decode("OID39ZY06FGSCTVN4T2V9PKHFZ"),
+        Err(DecodeError::InvalidChar)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that alias decoding still enforces the length and rejects characters outside the
+/// alphabet and its aliases.
+#[test]
+fn decode_crockford_aliases_error() {
+    assert_eq!(
+        decode_crockford_aliases("OID39ZY06FGSCTVN4T2V9PKHF"),
+        Err(DecodeError::InvalidLength)
+    );
+    assert_eq!(
+        decode_crockford_aliases("OID39ZY06FGSCTVN4T2V9PKHFU"),
+        Err(DecodeError::InvalidChar)
+    );
+}
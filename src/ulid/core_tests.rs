@@ -0,0 +1,150 @@
+//! Verifies the `core`-only ULID surface used without the `std` feature.
+//!
+//! Bounded unit under test:
+//! - `ULID`
+//! - `base32`
+//!
+//! Public interfaces verified:
+//! - `ULID::from_string`
+//! - `ULID::from_parts`
+//! - `ULID::from_bytes`
+//! - `ULID::to_bytes`
+//! - `ULID::from_bytes_le`
+//! - `ULID::to_bytes_le`
+//! - `ULID::array_to_str`
+//! - `ULID::verify_roundtrip`
+//! - `ULID::const_cmp`
+//! - `base32::decode`
+//! - `base32::encode_to_array`
+//!
+//! Logical paths covered:
+//! - the const constructors and conversions evaluate in a const context
+//! - little-endian bytes round-trip and reverse the big-endian bytes
+//! - const comparison agrees with `Ord` and works in a const assertion
+//! - fixed-buffer encoding round-trips through decoding
+//! - nil, maximum, minted, and timestamp-bound ULIDs verify their round trip
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+//!
+//! This module only uses the `core` surface, so it also runs under
+//! `cargo test --lib --no-default-features`.
+
+use crate::ulid::ULID;
+use crate::ulid::base32::{self, DecodeError, ULID_LEN};
+use test_framework_oss::{is_error, is_ok};
+
+const TEXT: &str = "01D39ZY06FGSCTVN4T2V9PKHFZ";
+
+const PARSED: Result<ULID, DecodeError> = ULID::from_string(TEXT);
+const DECODED: Result<u128, DecodeError> = base32::decode(TEXT);
+const FROM_PARTS: ULID = ULID::from_parts(1_700_000_000_000, 42);
+const BYTES: [u8; 16] = FROM_PARTS.to_bytes();
+const FROM_BYTES: ULID = ULID::from_bytes(BYTES);
+const RANGE_START: ULID = ULID::min_for_timestamp(1_700_000_000_000);
+const RANGE_END: ULID = ULID::max_for_timestamp(1_700_000_000_000);
+const _: () =
+    assert!(RANGE_START.const_cmp(&FROM_PARTS) <= 0 && FROM_PARTS.const_cmp(&RANGE_END) <= 0);
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the const constructors and conversions evaluate at compile time.
+#[test]
+fn const_functions_success() {
+    assert_eq!(is_ok!(PARSED).value(), is_ok!(DECODED));
+    assert_eq!(FROM_BYTES, FROM_PARTS);
+    assert_eq!(
+        is_error!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHF")),
+        DecodeError::InvalidLength
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that encoding into a fixed buffer round-trips through decoding.
+#[test]
+fn fixed_buffer_round_trip_success() {
+    let mut buffer = [0u8; ULID_LEN];
+    base32::encode_to_array(FROM_PARTS.value(), &mut buffer);
+    let encoded = is_ok!(core::str::from_utf8(&buffer));
+    assert_eq!(is_ok!(base32::decode(encoded)), FROM_PARTS.value());
+
+    let mut text_buffer = [0u8; ULID_LEN];
+    let text = FROM_PARTS.array_to_str(&mut text_buffer);
+    assert_eq!(is_ok!(ULID::from_string(text)), FROM_PARTS);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that nil, maximum, minted, and timestamp-bound ULIDs all round-trip through the
+/// canonical codec.
+#[test]
+fn verify_roundtrip_success() {
+    let ulids = [
+        ULID::nil(),
+        ULID::from_u128(u128::MAX),
+        FROM_PARTS,
+        is_ok!(PARSED),
+        ULID::min_for_timestamp(1_700_000_000_000),
+        ULID::max_for_timestamp(1_700_000_000_000),
+        ULID::from_u128(1),
+    ];
+
+    for ulid in ulids {
+        assert!(ulid.verify_roundtrip(), "expected {:?} to round-trip", ulid);
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that little-endian bytes round-trip for boundary and minted ULIDs.
+#[test]
+fn bytes_le_round_trip_success() {
+    let ulids = [
+        ULID::nil(),
+        ULID::from_u128(u128::MAX),
+        ULID::from_u128(1),
+        FROM_PARTS,
+    ];
+
+    for ulid in ulids {
+        assert_eq!(ULID::from_bytes_le(ulid.to_bytes_le()), ulid);
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the little-endian bytes are the big-endian bytes reversed for a known value.
+#[test]
+fn to_bytes_le_reverses_to_bytes_success() {
+    let ulid = ULID::from_u128(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10);
+
+    assert_eq!(
+        ulid.to_bytes_le(),
+        [
+            0x10, 0x0F, 0x0E, 0x0D, 0x0C, 0x0B, 0x0A, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03,
+            0x02, 0x01
+        ]
+    );
+    let mut reversed = ulid.to_bytes();
+    reversed.reverse();
+    assert_eq!(ulid.to_bytes_le(), reversed);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that `const_cmp` reports less, equal, and greater consistently with `Ord`.
+#[test]
+fn const_cmp_success() {
+    let lower = ULID::from_u128(1);
+    let upper = ULID::from_u128(u128::MAX);
+
+    assert_eq!(lower.const_cmp(&upper), -1);
+    assert_eq!(lower.const_cmp(&lower), 0);
+    assert_eq!(upper.const_cmp(&lower), 1);
+    assert_eq!(ULID::nil().const_cmp(&lower), -1);
+
+    for (left, right) in [(lower, upper), (upper, lower), (FROM_PARTS, FROM_PARTS)] {
+        assert_eq!(left.const_cmp(&right), left.cmp(&right) as i8);
+    }
+}
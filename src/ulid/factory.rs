@@ -0,0 +1,65 @@
+//! Infallible [`ULID`] sources for code that needs reproducible identities.
+//!
+//! Production identities come from the fallible
+//! [`NewIdentityGW`](crate::gateway::new_identity::NewIdentityGW) seam. Entity unit tests often
+//! only need a predictable, ordered sequence, which [`SequentialUlidFactory`] provides without
+//! stubbing a gateway or unwrapping its `Result`.
+
+use crate::ulid::ULID;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Produces a new [`ULID`] on every call, without an error channel.
+pub trait UlidFactory: Send + Sync {
+    /// Returns the next [`ULID`].
+    fn next(&self) -> ULID;
+}
+
+/// A [`UlidFactory`] that counts up from a base [`ULID`].
+///
+/// The first call returns the base and each later call returns the previous value with its random
+/// portion incremented, exactly as [`ULID::increment`] would, so successive values keep the base's
+/// timestamp and are strictly increasing. The counter is atomic, so the factory can be shared
+/// across threads; each value is still handed out once.
+#[derive(Debug)]
+pub struct SequentialUlidFactory {
+    /// The first value returned.
+    base: ULID,
+    /// The number of values already returned.
+    issued: AtomicU64,
+}
+
+impl SequentialUlidFactory {
+    /// Creates a factory whose first value is `base`.
+    pub fn new(base: ULID) -> Self {
+        SequentialUlidFactory {
+            base,
+            issued: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the first value this factory returns.
+    pub fn base(&self) -> ULID {
+        self.base
+    }
+}
+
+impl UlidFactory for SequentialUlidFactory {
+    /// Returns the base advanced by the number of values already issued.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the random portion of the base cannot be advanced further without changing the
+    /// timestamp, the point where [`ULID::increment`] returns `None`.
+    fn next(&self) -> ULID {
+        let offset = u128::from(self.issued.fetch_add(1, Ordering::Relaxed));
+        match self.base.random().checked_add(offset) {
+            Some(random) if random <= crate::bitmask!(ULID::RAND_BITS) => {
+                ULID::from_parts(self.base.timestamp_ms(), random)
+            }
+            _ => panic!(
+                "The sequential ULID factory based at {:?} has no random values left for its timestamp.",
+                self.base
+            ),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Verifies the infallible ULID factory.
+//!
+//! Bounded unit under test:
+//! - `UlidFactory`
+//! - `SequentialUlidFactory`
+//!
+//! Public interfaces verified:
+//! - `SequentialUlidFactory::new`
+//! - `SequentialUlidFactory::base`
+//! - `UlidFactory::next`
+//!
+//! Logical paths covered:
+//! - the first value is the base and successive values are strictly increasing with the same timestamp
+//! - values match repeated `ULID::increment` calls
+//! - a factory shared across threads hands out each value once
+//! - a factory whose random portion is exhausted panics
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use crate::ulid::ULID;
+use crate::ulid::factory::{SequentialUlidFactory, UlidFactory};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the factory starts at its base and that successive values are strictly
+/// increasing, keep the base timestamp, and match repeated `ULID::increment` calls.
+#[test]
+fn sequential_next_increasing_success() {
+    let base = ULID::from_parts(1_700_000_000_000, 42);
+    let factory = SequentialUlidFactory::new(base);
+    assert_eq!(factory.base(), base);
+
+    let values: Vec<ULID> = (0..5).map(|_| factory.next()).collect();
+
+    assert_eq!(values[0], base);
+    let mut expected = base;
+    for pair in values.windows(2) {
+        assert!(pair[0] < pair[1]);
+        assert_eq!(pair[1].timestamp_ms(), base.timestamp_ms());
+        expected = match expected.increment() {
+            Some(next) => next,
+            None => panic!("expected room to increment"),
+        };
+        assert_eq!(pair[1], expected);
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a factory shared across threads, including as a trait object, hands out each
+/// value exactly once.
+#[test]
+fn sequential_next_shared_success() {
+    let factory: Arc<dyn UlidFactory> = Arc::new(SequentialUlidFactory::new(ULID::nil()));
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let factory = Arc::clone(&factory);
+            std::thread::spawn(move || (0..100).map(|_| factory.next()).collect::<Vec<_>>())
+        })
+        .collect();
+    let mut seen = HashSet::new();
+    for handle in handles {
+        let Ok(ulids) = handle.join() else {
+            panic!("expected the thread to finish");
+        };
+        for ulid in ulids {
+            assert!(seen.insert(ulid));
+        }
+    }
+
+    assert_eq!(seen.len(), 400);
+    assert_eq!(seen.iter().max(), Some(&ULID::from_u128(399)));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the factory panics instead of rolling into the next timestamp once the random
+/// portion is exhausted.
+#[test]
+#[should_panic(expected = "has no random values left for its timestamp")]
+fn sequential_next_exhausted_error() {
+    let factory = SequentialUlidFactory::new(ULID::max_for_timestamp(1_700_000_000_000));
+
+    assert_eq!(factory.next(), ULID::max_for_timestamp(1_700_000_000_000));
+    factory.next();
+}
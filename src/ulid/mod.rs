@@ -21,16 +21,33 @@
 // TODO - LEFT OFF - Get all the code ported and the test working...look at their wasm test as well.
 
 pub mod base32;
+#[cfg(feature = "std")]
+pub mod factory;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod base32_tests;
 #[cfg(test)]
+mod core_tests;
+#[cfg(all(test, feature = "std"))]
+mod factory_tests;
+#[cfg(all(test, feature = "std"))]
 mod ulid_tests;
 
+#[cfg(feature = "std")]
+use crate::error::{Error, Kind};
+#[cfg(feature = "std")]
+use crate::ulid::base32::AlphabetError;
 use crate::ulid::base32::{DecodeError, ULID_LEN};
+#[cfg(feature = "std")]
 use crate::values::Value;
-use std::fmt;
-use std::str::FromStr;
+#[cfg(feature = "std")]
+use crate::values::datetime::utc_timestamp::UTCTimestamp;
+#[cfg(feature = "std")]
+use crate::values::uri::decode_component_strict;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt;
+use core::str::FromStr;
 
 /// Creates a right-aligned bitmask with the requested number of bits.
 #[macro_export]
@@ -54,11 +71,46 @@ pub struct ULID(
     pub u128,
 );
 
+/// An error that can occur when splitting a prefixed ULID with [`ULID::from_prefixed`]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum PrefixError {
+    /// The identifier has no `_` separator or an empty prefix
+    MissingPrefix,
+    /// The part after the separator is not a valid ULID
+    Decode(DecodeError),
+}
+
+impl core::error::Error for PrefixError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PrefixError::MissingPrefix => None,
+            PrefixError::Decode(error) => Some(error),
+        }
+    }
+}
+
+impl fmt::Display for PrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            PrefixError::MissingPrefix => write!(f, "missing prefix"),
+            PrefixError::Decode(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<DecodeError> for PrefixError {
+    fn from(error: DecodeError) -> Self {
+        PrefixError::Decode(error)
+    }
+}
+
 impl ULID {
     /// The number of bits in a [ULID]'s time portion
     pub const TIME_BITS: u8 = 48;
     /// The number of bits in a [ULID]'s random portion
     pub const RAND_BITS: u8 = 80;
+    /// The largest timestamp, in milliseconds, that fits in a [ULID]'s time portion
+    pub const MAX_TIMESTAMP: u64 = (1 << Self::TIME_BITS) - 1;
 
     /// Returns the numeric value contained by this [`ULID`].
     pub const fn value(&self) -> u128 {
@@ -85,6 +137,39 @@ impl ULID {
         ULID((time_part << Self::RAND_BITS) | rand_part)
     }
 
+    /// Create a [ULID] from separated parts, rejecting a timestamp that does not fit.
+    ///
+    /// Unlike [`ULID::from_parts`], a timestamp past the 48-bit range is an error instead of
+    /// being wrapped. Overflow bits in `random` are still discarded.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::System`](crate::error::Audience::System)
+    /// is returned when `timestamp_ms` needs more than [`ULID::TIME_BITS`] bits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::try_from_parts(1_700_000_000_000, 42).unwrap();
+    ///
+    /// assert_eq!(ulid, ULID::from_parts(1_700_000_000_000, 42));
+    /// assert!(ULID::try_from_parts(1 << ULID::TIME_BITS, 42).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_parts(timestamp_ms: u64, random: u128) -> Result<ULID, Error> {
+        if timestamp_ms > Self::MAX_TIMESTAMP {
+            return Err(Error::for_system(
+                Kind::InvalidInput,
+                format!(
+                    "The timestamp of {} ms is past the largest ULID timestamp.",
+                    timestamp_ms
+                ),
+            ));
+        }
+        Ok(ULID::from_parts(timestamp_ms, random))
+    }
+
     /// Creates a [ULID] from a Crockford Base32 encoded string
     ///
     /// An DecodeError will be returned when the given string is not formatted
@@ -107,6 +192,107 @@ impl ULID {
         }
     }
 
+    /// Returns `true` when `encoded` decodes to this [ULID].
+    ///
+    /// The comparison decodes `encoded` instead of encoding `self`, so it does not allocate, and
+    /// lowercase input compares equal to its canonical form. Malformed input returns `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    ///
+    /// assert!(ulid.eq_str("01d39zy06fgsctvn4t2v9pkhfz"));
+    /// assert!(!ulid.eq_str("not a ulid"));
+    /// ```
+    pub const fn eq_str(&self, encoded: &str) -> bool {
+        match base32::decode(encoded) {
+            Ok(value) => value == self.0,
+            Err(_) => false,
+        }
+    }
+
+    /// Normalizes a Crockford Base32 encoded [ULID] string to its canonical form.
+    ///
+    /// The input is decoded and re-encoded, so lowercase and alternate characters are returned as
+    /// the canonical uppercase 26 character string. Use this before storing or indexing ULID text.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User)
+    /// is returned when the input is not a valid encoded [ULID].
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let normalized = ULID::normalize_string("01d39zy06fgsctvn4t2v9pkhfz").unwrap();
+    ///
+    /// assert_eq!(normalized, "01D39ZY06FGSCTVN4T2V9PKHFZ");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn normalize_string(encoded: &str) -> Result<String, Error> {
+        ULID::from_string(encoded)
+            .map(|ulid| ulid.to_string())
+            .map_err(|error| invalid_ulid_error(encoded, error))
+    }
+
+    /// Decodes each of `inputs`, returning one result per input in the same order.
+    ///
+    /// Use this for bulk imports that report a result per row instead of stopping at the first
+    /// invalid value.
+    ///
+    /// # Errors
+    ///
+    /// Each invalid input yields the same [`Error`] of [`Kind::InvalidInput`] for
+    /// [`Audience::User`](crate::error::Audience::User) as [`ULID::normalize_string`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let results = ULID::decode_batch(&["01D39ZY06FGSCTVN4T2V9PKHFZ", "invalid"]);
+    ///
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn decode_batch(inputs: &[&str]) -> Vec<Result<ULID, Error>> {
+        inputs
+            .iter()
+            .map(|encoded| {
+                ULID::from_string(encoded).map_err(|error| invalid_ulid_error(encoded, error))
+            })
+            .collect()
+    }
+
+    /// Parses a [ULID] taken from a URL path segment or query value, decoding `%XX` escapes first.
+    ///
+    /// The component is decoded with [`decode_component_strict`], so a `%` that is not followed
+    /// by two hexadecimal digits is rejected, since it means the identifier was truncated or
+    /// mangled in transit.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User)
+    /// is returned for a malformed escape, or, as from [`ULID::normalize_string`], when the decoded
+    /// text is not a valid encoded [ULID].
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKH%46Z").unwrap();
+    ///
+    /// assert!(ulid.eq_str("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_url_component(component: &str) -> Result<ULID, Error> {
+        let text = decode_component_strict(component)?;
+        ULID::from_string(&text).map_err(|error| invalid_ulid_error(component, error))
+    }
+
     /// The 'nil [ULID]'.
     ///
     /// The nil [ULID] is special form of [ULID] that is specified to have all 128 bits set to zero.
@@ -142,6 +328,111 @@ impl ULID {
         self.0 & bitmask!(Self::RAND_BITS)
     }
 
+    /// Gets the millisecond timestamp section of this ulid.
+    pub const fn timestamp_ms(&self) -> u64 {
+        (self.0 >> Self::RAND_BITS) as u64
+    }
+
+    /// Returns `true` when this ulid was minted within `[min, max]`, inclusive.
+    ///
+    /// The embedded millisecond timestamp is compared with [`UTCTimestamp::as_milli`] of both
+    /// bounds, so sub-millisecond precision in `min` and `max` is ignored. The bounds are compared
+    /// as full `u64` values and never truncated to 48 bits, so a bound past
+    /// [`ULID::MAX_TIMESTAMP`] does not wrap.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    /// use kernel_oss::values::datetime::utc_timestamp::UTCTimestamp;
+    ///
+    /// let min = UTCTimestamp::builder().use_ms(1_000).build().unwrap();
+    /// let max = UTCTimestamp::builder().use_ms(2_000).build().unwrap();
+    ///
+    /// assert!(ULID::from_parts(1_500, 42).timestamp_within(&min, &max));
+    /// assert!(!ULID::from_parts(2_001, 0).timestamp_within(&min, &max));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn timestamp_within(&self, min: &UTCTimestamp, max: &UTCTimestamp) -> bool {
+        (min.as_milli()..=max.as_milli()).contains(&self.timestamp_ms())
+    }
+
+    /// Returns the smallest [ULID] that carries `timestamp_ms`, with all random bits cleared.
+    ///
+    /// Any [ULID] minted at `timestamp_ms` sorts within
+    /// `[ULID::min_for_timestamp(timestamp_ms), ULID::max_for_timestamp(timestamp_ms)]`, so the
+    /// pair forms inclusive bounds for a lexicographic range scan in a key-value store. Bounds from
+    /// two different timestamps cover every [ULID] minted between them.
+    ///
+    /// A `timestamp_ms` past [`ULID::MAX_TIMESTAMP`] is clamped to it rather than wrapped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let min = ULID::min_for_timestamp(1_700_000_000_000);
+    /// let max = ULID::max_for_timestamp(1_700_000_000_000);
+    /// let ulid = ULID::from_parts(1_700_000_000_000, 42);
+    ///
+    /// assert!(min <= ulid && ulid <= max);
+    /// ```
+    pub const fn min_for_timestamp(timestamp_ms: u64) -> ULID {
+        ULID::from_parts(saturate_timestamp(timestamp_ms), 0)
+    }
+
+    /// Returns a stable lower-bound [ULID] for range scans over one namespace, such as an entity type.
+    ///
+    /// The timestamp is zero and the random bits hold a 64-bit FNV-1a hash of `namespace`, so
+    /// each namespace gets its own fixed sentinel and every [ULID] minted after the Unix epoch
+    /// sorts after it. No caller timestamp is involved, so nothing can be truncated.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let sentinel = ULID::namespace_min("procedure");
+    ///
+    /// assert_eq!(sentinel, ULID::namespace_min("procedure"));
+    /// assert!(sentinel < ULID::min_for_timestamp(1));
+    /// ```
+    pub const fn namespace_min(namespace: &str) -> ULID {
+        ULID::from_parts(0, fnv1a_64(namespace.as_bytes()) as u128)
+    }
+
+    /// Returns the largest [ULID] that carries `timestamp_ms`, with all random bits set.
+    ///
+    /// This is the inclusive upper bound matching [`ULID::min_for_timestamp`], and clamps a
+    /// `timestamp_ms` past [`ULID::MAX_TIMESTAMP`] the same way.
+    pub const fn max_for_timestamp(timestamp_ms: u64) -> ULID {
+        ULID::from_parts(saturate_timestamp(timestamp_ms), bitmask!(Self::RAND_BITS))
+    }
+
+    /// Returns the inclusive [ULID] bounds for every identity minted during one UTC day.
+    ///
+    /// `day_millis_start` is the unix timestamp, in milliseconds, at the start of the day. The
+    /// lower bound carries that timestamp with all random bits cleared, and the upper bound carries
+    /// the last millisecond of the day with all random bits set, so a lexicographic range scan over
+    /// `[start, end]` selects every entity created on that day. Bounds past
+    /// [`ULID::MAX_TIMESTAMP`] are clamped to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let (start, end) = ULID::range_for_day(1_700_006_400_000);
+    /// let ulid = ULID::from_parts(1_700_050_000_000, 42);
+    ///
+    /// assert!(start <= ulid && ulid <= end);
+    /// ```
+    pub const fn range_for_day(day_millis_start: u64) -> (ULID, ULID) {
+        const DAY_MILLIS: u64 = 86_400_000;
+
+        let day_millis_end = day_millis_start.saturating_add(DAY_MILLIS - 1);
+        (
+            ULID::min_for_timestamp(day_millis_start),
+            ULID::max_for_timestamp(day_millis_end),
+        )
+    }
+
     /// Creates a Crockford Base32 encoded string that represents this [ULID]
     ///
     /// # Example
@@ -162,6 +453,24 @@ impl ULID {
         unsafe { core::str::from_utf8_unchecked_mut(buf) }
     }
 
+    /// Returns `true` when encoding this [ULID] and decoding the text yields the same [ULID].
+    ///
+    /// The canonical codec always round-trips, so this is a property check for tests that wire in
+    /// new encoding variants. It encodes into a stack buffer and needs no allocator.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// assert!(ULID::nil().verify_roundtrip());
+    /// assert!(ULID::from_u128(u128::MAX).verify_roundtrip());
+    /// ```
+    pub fn verify_roundtrip(&self) -> bool {
+        let mut buf = [0; ULID_LEN];
+        let text = self.array_to_str(&mut buf);
+        matches!(ULID::from_string(text), Ok(decoded) if decoded == *self)
+    }
+
     /// Creates a Crockford Base32 encoded string that represents this [ULID]
     ///
     /// # Example
@@ -173,20 +482,90 @@ impl ULID {
     ///
     /// assert_eq!(&ulid.to_string(), text);
     /// ```
+    #[cfg(feature = "alloc")]
     #[allow(clippy::inherent_to_string_shadow_display)] // Significantly faster than Display::to_string
     pub fn to_string(&self) -> String {
         base32::encode(self.0)
     }
 
+    /// Creates a typed identifier such as `usr_01D39ZY06FGSCTVN4T2V9PKHFZ` by joining `prefix`
+    /// and the canonical encoding with `_`.
+    ///
+    /// The prefix is not validated; read it back with [`ULID::from_prefixed`], which splits on
+    /// the last `_`, so a prefix may itself contain `_`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    ///
+    /// assert_eq!(ulid.to_prefixed("usr"), "usr_01D39ZY06FGSCTVN4T2V9PKHFZ");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_prefixed(&self, prefix: &str) -> String {
+        let mut buf = [0; ULID_LEN];
+        format!("{}_{}", prefix, self.array_to_str(&mut buf))
+    }
+
+    /// Splits a typed identifier created by [`ULID::to_prefixed`] into its prefix and [ULID].
+    ///
+    /// The input is split on the last `_`; the part after it is decoded like
+    /// [`ULID::from_string`].
+    ///
+    /// # Errors
+    ///
+    /// [`PrefixError::MissingPrefix`] when there is no `_` or the prefix before it is empty, or
+    /// [`PrefixError::Decode`] with the [`ULID::from_string`] error for the part after it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let (prefix, ulid) = ULID::from_prefixed("usr_01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    ///
+    /// assert_eq!(prefix, "usr");
+    /// assert_eq!(ulid, ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_prefixed(prefixed: &str) -> Result<(String, ULID), PrefixError> {
+        match prefixed.rsplit_once('_') {
+            Some((prefix, encoded)) if !prefix.is_empty() => {
+                let ulid = ULID::from_string(encoded)?;
+                Ok((String::from(prefix), ulid))
+            }
+            _ => Err(PrefixError::MissingPrefix),
+        }
+    }
+
+    /// Returns a stable shard index in `0..shard_count` taken from the random bits.
+    ///
+    /// The timestamp is ignored, so identities minted together spread across shards instead of
+    /// crowding one. A `shard_count` of `0` returns `0` rather than dividing by zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_parts(1_700_000_000_000, 42);
+    ///
+    /// assert_eq!(ulid.shard(16), 10);
+    /// assert_eq!(ulid.shard(0), 0);
+    /// ```
+    pub const fn shard(&self, shard_count: u16) -> u16 {
+        if shard_count == 0 {
+            return 0;
+        }
+        (self.random() % shard_count as u128) as u16
+    }
+
     /// Test if the [ULID] is nil
     ///
     /// # Example
     /// ```rust
     /// use kernel_oss::ulid::ULID;
     ///
-    /// # let ulid = ULID(1);
-    /// # #[cfg(feature = "std")]
-    /// let ulid = ULID::new();
+    /// let ulid = ULID::from_parts(1_700_000_000_000, 42);
     /// assert!(!ulid.is_nil());
     ///
     /// let nil = ULID::nil();
@@ -196,6 +575,28 @@ impl ULID {
         self.0 == 0u128
     }
 
+    /// Compares two [ULID]s in a `const` context, returning `-1`, `0`, or `1`.
+    ///
+    /// The result matches [`Ord::cmp`], which cannot be called from a `const fn`.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// const LOWER: ULID = ULID::min_for_timestamp(1_700_000_000_000);
+    /// const UPPER: ULID = ULID::max_for_timestamp(1_700_000_000_000);
+    /// const _: () = assert!(LOWER.const_cmp(&UPPER) < 0);
+    /// ```
+    pub const fn const_cmp(&self, other: &ULID) -> i8 {
+        if self.0 < other.0 {
+            -1
+        } else if self.0 > other.0 {
+            1
+        } else {
+            0
+        }
+    }
+
     /// Increment the random number, make sure that the ts millis stays the same
     pub const fn increment(&self) -> Option<ULID> {
         const MAX_RANDOM: u128 = bitmask!(ULID::RAND_BITS);
@@ -239,8 +640,266 @@ impl ULID {
     pub const fn to_bytes(&self) -> [u8; 16] {
         self.0.to_be_bytes()
     }
+
+    /// Creates a [ULID] from bytes in little-endian order.
+    ///
+    /// This is only for interop with binary protocols and databases that store 128-bit values
+    /// little-endian. The ULID value is the same as with [`ULID::from_bytes`] on the reversed
+    /// bytes, so the sort order of its string form is unaffected.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_parts(1_700_000_000_000, 42);
+    ///
+    /// assert_eq!(ULID::from_bytes_le(ulid.to_bytes_le()), ulid);
+    /// ```
+    pub const fn from_bytes_le(bytes: [u8; 16]) -> ULID {
+        Self(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns the bytes of the [ULID] in little-endian order, the reverse of [`ULID::to_bytes`].
+    ///
+    /// Only use this for byte-layout interop. Comparing little-endian byte arrays does not follow
+    /// ULID order, but the string form and the big-endian bytes still sort chronologically.
+    pub const fn to_bytes_le(&self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// Creates a [ULID] from its raw 128-bit value.
+    ///
+    /// This is the `const` equivalent of `ULID::from(value)` and of the tuple constructor.
+    pub const fn from_u128(value: u128) -> ULID {
+        ULID(value)
+    }
+
+    /// Creates a [ULID] from two `u64` halves.
+    ///
+    /// `msb` holds the most significant 64 bits (the 48-bit timestamp and the top 16 random bits),
+    /// and `lsb` holds the least significant 64 random bits. This matches the big-endian byte
+    /// layout of [`ULID::to_bytes`]: `msb.to_be_bytes()` is bytes `0..8` and `lsb.to_be_bytes()`
+    /// is bytes `8..16`. The `From<(u64, u64)>` conversion uses the same layout.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_u64_pair(0x0102_0304_0506_0708, 0x090A_0B0C_0D0E_0F10);
+    ///
+    /// assert_eq!(ulid.to_bytes(), [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    /// assert_eq!(ulid.to_u64_pair(), (0x0102_0304_0506_0708, 0x090A_0B0C_0D0E_0F10));
+    /// ```
+    pub const fn from_u64_pair(msb: u64, lsb: u64) -> ULID {
+        ULID(((msb as u128) << 64) | lsb as u128)
+    }
+
+    /// Returns the `(msb, lsb)` halves of this [ULID], the inverse of [`ULID::from_u64_pair`].
+    pub const fn to_u64_pair(&self) -> (u64, u64) {
+        ((self.0 >> 64) as u64, self.0 as u64)
+    }
+
+    /// Returns a weak HTTP ETag, such as `W/"94PHJ5JMAK46F"`, derived from this [ULID].
+    ///
+    /// The tag is a 64-bit FNV-1a hash of [`ULID::to_bytes`] rendered as 13 Crockford Base32
+    /// characters, so it is stable across processes and releases for the same [ULID].
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ").unwrap();
+    ///
+    /// assert_eq!(ulid.etag(), ulid.etag());
+    /// assert!(ulid.etag().starts_with("W/\""));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn etag(&self) -> String {
+        const HASH_LEN: usize = 13;
+
+        let hash = fnv1a_64(&self.to_bytes());
+        let encoded: String = (0..HASH_LEN)
+            .rev()
+            .map(|index| char::from(base32::ALPHABET[((hash >> (index * 5)) & 0x1f) as usize]))
+            .collect();
+
+        format!("W/\"{}\"", encoded)
+    }
+
+    /// Reads a [ULID] from the 16 big-endian bytes starting at `offset` in `buf`.
+    ///
+    /// Use this when a binary wire format embeds a [ULID] at a known offset.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::System`](crate::error::Audience::System)
+    /// is returned when `buf` does not contain 16 bytes starting at `offset`.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let mut buf = vec![0xAA; 4];
+    /// buf.extend_from_slice(&[0xFF; 16]);
+    ///
+    /// let ulid = ULID::read_from(&buf, 4).unwrap();
+    ///
+    /// assert_eq!(ulid.to_bytes(), [0xFF; 16]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_from(buf: &[u8], offset: usize) -> Result<ULID, Error> {
+        let bytes = offset
+            .checked_add(16)
+            .and_then(|end| buf.get(offset..end))
+            .ok_or_else(|| {
+                Error::for_system(
+                    Kind::InvalidInput,
+                    format!(
+                        "The buffer of {} bytes is too short to read a ULID at offset {}. A ULID requires 16 bytes.",
+                        buf.len(),
+                        offset
+                    ),
+                )
+            })?;
+
+        let mut ulid_bytes = [0u8; 16];
+        ulid_bytes.copy_from_slice(bytes);
+        Ok(ULID::from_bytes(ulid_bytes))
+    }
+
+    /// Writes this [ULID] as 16 big-endian bytes starting at `offset` in `buf`.
+    ///
+    /// This is the inverse of [`ULID::read_from`]. Bytes outside the written range are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::System`](crate::error::Audience::System)
+    /// is returned when `buf` does not have room for 16 bytes starting at `offset`. The buffer is not modified.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::ulid::ULID;
+    ///
+    /// let ulid = ULID::from_string("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap();
+    /// let mut buf = [0u8; 20];
+    ///
+    /// ulid.write_to(&mut buf, 2).unwrap();
+    ///
+    /// assert_eq!(ULID::read_from(&buf, 2).unwrap(), ulid);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to(&self, buf: &mut [u8], offset: usize) -> Result<(), Error> {
+        let buf_len = buf.len();
+        let target = offset
+            .checked_add(16)
+            .and_then(|end| buf.get_mut(offset..end))
+            .ok_or_else(|| {
+                Error::for_system(
+                    Kind::InvalidInput,
+                    format!(
+                        "The buffer of {} bytes is too short to write a ULID at offset {}. A ULID requires 16 bytes.",
+                        buf_len, offset
+                    ),
+                )
+            })?;
+
+        target.copy_from_slice(&self.to_bytes());
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a hash; stable across processes and releases, unlike the std hasher.
+const fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut index = 0;
+    while index < bytes.len() {
+        hash = (hash ^ bytes[index] as u64).wrapping_mul(FNV_PRIME);
+        index += 1;
+    }
+    hash
+}
+
+/// Clamps `timestamp_ms` to [`ULID::MAX_TIMESTAMP`] so bounds never wrap to the epoch.
+const fn saturate_timestamp(timestamp_ms: u64) -> u64 {
+    if timestamp_ms > ULID::MAX_TIMESTAMP {
+        ULID::MAX_TIMESTAMP
+    } else {
+        timestamp_ms
+    }
+}
+
+/// Maps a [`DecodeError`] for `encoded` to a kernel [`Error`] with the audience and kind of
+/// `Error::from(error)`, quoting the rejected value.
+#[cfg(feature = "std")]
+fn invalid_ulid_error(encoded: &str, error: DecodeError) -> Error {
+    let converted = Error::from(error);
+    Error::new(
+        converted.audience(),
+        converted.kind(),
+        format!("The value '{}' is not a valid ULID: {}.", encoded, error),
+    )
+}
+
+/// Converts a [`DecodeError`] into a kernel [`Error`] so `ULID::from_string(s)?` works in
+/// functions returning the kernel `Result`.
+///
+/// Length and character problems are [`Kind::InvalidInput`] for
+/// [`Audience::User`](crate::error::Audience::User), since the text usually came from a caller.
+/// Use [`ULID::normalize_string`] instead when the message should quote the rejected value.
+#[cfg(feature = "std")]
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        match error {
+            DecodeError::InvalidLength => Error::for_user(
+                Kind::InvalidInput,
+                format!("A ULID must be exactly {} characters long.", ULID_LEN),
+            ),
+            DecodeError::InvalidChar => Error::for_user(
+                Kind::InvalidInput,
+                "A ULID may only contain Crockford Base32 characters: 0-9 and A-Z except I, L, O, and U.",
+            ),
+        }
+    }
+}
+
+/// Converts a [`PrefixError`] into a kernel [`Error`] for
+/// [`Audience::User`](crate::error::Audience::User); decode problems convert like
+/// [`DecodeError`].
+#[cfg(feature = "std")]
+impl From<PrefixError> for Error {
+    fn from(error: PrefixError) -> Self {
+        match error {
+            PrefixError::MissingPrefix => Error::for_user(
+                Kind::InvalidInput,
+                "A prefixed ULID must have the form 'prefix_ULID' with a non-empty prefix.",
+            ),
+            PrefixError::Decode(error) => Error::from(error),
+        }
+    }
+}
+
+/// Converts an [`AlphabetError`] into a kernel [`Error`].
+///
+/// A bad alphabet is chosen in code, so it is for
+/// [`Audience::System`](crate::error::Audience::System); decode problems convert like
+/// [`DecodeError`].
+#[cfg(feature = "std")]
+impl From<AlphabetError> for Error {
+    fn from(error: AlphabetError) -> Self {
+        match error {
+            AlphabetError::InvalidAlphabet => Error::for_system(
+                Kind::InvalidInput,
+                "A custom base32 alphabet must contain 32 distinct ASCII characters.",
+            ),
+            AlphabetError::Decode(error) => Error::from(error),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
 impl Value for ULID {
     type ValueType = u128;
 
@@ -255,6 +914,7 @@ impl Default for ULID {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<ULID> for String {
     fn from(ulid: ULID) -> String {
         ulid.to_string()
@@ -263,13 +923,13 @@ impl From<ULID> for String {
 
 impl From<(u64, u64)> for ULID {
     fn from((msb, lsb): (u64, u64)) -> Self {
-        ULID(u128::from(msb) << 64 | u128::from(lsb))
+        ULID::from_u64_pair(msb, lsb)
     }
 }
 
 impl From<ULID> for (u64, u64) {
     fn from(ulid: ULID) -> (u64, u64) {
-        ((ulid.0 >> 64) as u64, (ulid.0 & bitmask!(64)) as u64)
+        ulid.to_u64_pair()
     }
 }
 
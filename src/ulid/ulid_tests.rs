@@ -6,27 +6,74 @@
 //! Public interfaces verified:
 //! - `ULID::from_string`
 //! - `ULID::from_str`
+//! - `ULID::normalize_string`
+//! - `ULID::decode_batch`
+//! - `ULID::from_url_component`
 //! - `ULID::increment`
 //! - `ULID::default`
+//! - `ULID::read_from`
+//! - `ULID::write_to`
+//! - `ULID::min_for_timestamp`
+//! - `ULID::max_for_timestamp`
+//! - `ULID::range_for_day`
+//! - `ULID::namespace_min`
+//! - `ULID::timestamp_ms`
+//! - `ULID::timestamp_within`
+//! - `ULID::etag`
+//! - `ULID::from_u64_pair`
+//! - `ULID::to_u64_pair`
+//! - `ULID::eq_str`
+//! - `ULID::to_prefixed`
+//! - `ULID::shard`
+//! - `ULID::from_prefixed`
+//! - `ULID::from_u128`
+//! - `ULID::try_from_parts`
 //! - `Display`
+//! - `From<DecodeError> for Error`
+//! - `From<PrefixError> for Error`
+//! - `From<AlphabetError> for Error`
 //! - conversion traits into string, integer, tuple, and bytes
 //!
 //! Logical paths covered:
 //! - string parsing succeeds for valid ULID values
 //! - string parsing supports canonical and alternate forms used by the module
+//! - normalization re-encodes lowercase input in canonical uppercase form
+//! - normalization rejects invalid input
+//! - batch decoding returns a per-index result for valid and invalid input
+//! - URL components decode plain and percent-encoded ULIDs and reject malformed escapes or invalid ULIDs
 //! - incrementing succeeds until the bounded maximum is reached
 //! - increment overflow returns no next value
 //! - display and conversion traits preserve the same ULID
+//! - prefixed identifiers round-trip, split on the last separator, and reject missing prefixes or bad ULIDs
+//! - shard indexes stay below the shard count, ignore the timestamp, spread sequential ids, and guard a zero count
+//! - each decode, prefix, and alphabet error converts to a kernel error with the expected kind, audience, and message
 //! - default returns the nil ULID
+//! - reading from a byte buffer succeeds at the start and at a non-zero offset
+//! - reading past the end of a byte buffer is rejected
+//! - writing into a byte buffer round-trips through `read_from` at any offset
+//! - writing past the end of a byte buffer is rejected and leaves the buffer unchanged
+//! - the checked constructor accepts timestamps up to the 48-bit limit and rejects larger ones
+//! - timestamp and day bounds past the 48-bit range clamp to the largest timestamp
+//! - ULIDs minted at a timestamp fall inside that timestamp's bounds and neighbours do not
+//! - ULIDs minted within a day fall inside that day's range and the next day's do not
+//! - namespace sentinels are stable, distinct per namespace, and sort before minted ULIDs
+//! - a ULID's timestamp is within a window inside and at its bounds and not outside it
+//! - window bounds past the 48-bit range do not wrap to the epoch
+//! - the etag is stable for a ULID and differs between ULIDs
+//! - the `(u64, u64)` halves follow the big-endian byte layout in both directions
+//! - string comparison matches equal and lowercase input and rejects unequal or malformed input
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
-use crate::ulid::ULID;
+use crate::error::{Audience, Error, Kind};
+use crate::ulid::base32::AlphabetError;
 use crate::ulid::base32::DecodeError;
 use crate::ulid::base32::EncodeError;
+use crate::ulid::{PrefixError, ULID};
+use crate::values::datetime::utc_timestamp::UTCTimestamp;
 use std::str::FromStr;
-use test_framework_oss::is_ok;
+use test_framework_oss::{is_ok, kernel_error_eq};
 
 /// Requirement validation: No requirement validation point is currently supplied.
 ///
@@ -109,4 +156,613 @@ fn display_success() {
     println!("{}", EncodeError::BufferTooSmall);
     println!("{}", DecodeError::InvalidLength);
     println!("{}", DecodeError::InvalidChar);
+    println!("{}", PrefixError::MissingPrefix);
+    println!("{}", PrefixError::Decode(DecodeError::InvalidChar));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a ULID is read from the first 16 bytes of a buffer.
+#[test]
+fn read_from_offset_zero_success() {
+    let expected = is_ok!(ULID::from_str("01FKMG6GAG0PJANMWFN84TNXCD"));
+    let mut buf = expected.to_bytes().to_vec();
+    buf.extend_from_slice(&[0xAA; 4]);
+
+    let actual = is_ok!(ULID::read_from(&buf, 0));
+
+    assert_eq!(actual, expected);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a ULID is read from 16 bytes starting at a non-zero offset.
+#[test]
+fn read_from_non_zero_offset_success() {
+    let expected = is_ok!(ULID::from_str("01FKMG6GAG0PJANMWFN84TNXCD"));
+    let mut buf = vec![0xAA; 7];
+    buf.extend_from_slice(&expected.to_bytes());
+
+    let actual = is_ok!(ULID::read_from(&buf, 7));
+
+    assert_eq!(actual, expected);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that reading a ULID past the end of the buffer is rejected.
+#[test]
+fn read_from_out_of_bounds_error() {
+    let buf = [0u8; 20];
+
+    kernel_error_eq!(
+        ULID::read_from(&buf, 5),
+        Kind::InvalidInput,
+        Audience::System,
+        "The buffer of 20 bytes is too short to read a ULID at offset 5. A ULID requires 16 bytes."
+    );
+    kernel_error_eq!(
+        ULID::read_from(&buf, usize::MAX),
+        Kind::InvalidInput,
+        Audience::System,
+        "The buffer of 20 bytes is too short to read a ULID at offset 18446744073709551615. A ULID requires 16 bytes."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that writing a ULID into a zeroed buffer places the big-endian
+/// bytes at the requested offset and round-trips through `read_from`.
+#[test]
+fn write_to_round_trip_success() {
+    let ulid = is_ok!(ULID::from_str("01FKMG6GAG0PJANMWFN84TNXCD"));
+
+    for offset in [0usize, 3, 8] {
+        let mut buf = [0u8; 24];
+
+        is_ok!(ulid.write_to(&mut buf, offset));
+
+        assert_eq!(&buf[offset..offset + 16], &ulid.to_bytes());
+        assert!(buf[..offset].iter().all(|byte| *byte == 0));
+        assert!(buf[offset + 16..].iter().all(|byte| *byte == 0));
+        assert_eq!(is_ok!(ULID::read_from(&buf, offset)), ulid);
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that writing a ULID past the end of the buffer is rejected and the
+/// buffer is left unchanged.
+#[test]
+fn write_to_out_of_bounds_error() {
+    let ulid = ULID(u128::MAX);
+    let mut buf = [0u8; 20];
+
+    kernel_error_eq!(
+        ulid.write_to(&mut buf, 5),
+        Kind::InvalidInput,
+        Audience::System,
+        "The buffer of 20 bytes is too short to write a ULID at offset 5. A ULID requires 16 bytes."
+    );
+    assert_eq!(buf, [0u8; 20]);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that ULIDs minted at a timestamp fall inside that timestamp's
+/// bounds, and that ULIDs from the neighbouring milliseconds fall outside them.
+#[test]
+fn timestamp_bounds_success() {
+    let timestamp: u64 = 1_700_000_000_000;
+    let min = ULID::min_for_timestamp(timestamp);
+    let max = ULID::max_for_timestamp(timestamp);
+
+    assert_eq!(min.value() >> ULID::RAND_BITS, u128::from(timestamp));
+    assert_eq!(max.value() >> ULID::RAND_BITS, u128::from(timestamp));
+    assert_eq!(min.random(), 0);
+    assert_eq!(max.increment(), None);
+
+    for random in [0, 1, 0x1234_5678_9ABC_DEF0, u128::MAX] {
+        let ulid = ULID::from_parts(timestamp, random);
+        assert!(
+            min <= ulid && ulid <= max,
+            "{ulid} should be within the bounds"
+        );
+    }
+    assert!(ULID::from_parts(timestamp - 1, u128::MAX) < min);
+    assert!(ULID::from_parts(timestamp + 1, 0) > max);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the checked constructor matches `from_parts` up to the largest timestamp and
+/// rejects a timestamp past it instead of wrapping.
+#[test]
+fn try_from_parts_timestamp_range() {
+    let largest = (1u64 << ULID::TIME_BITS) - 1;
+
+    assert_eq!(
+        is_ok!(ULID::try_from_parts(1_700_000_000_000, 42)),
+        ULID::from_parts(1_700_000_000_000, 42)
+    );
+    assert_eq!(
+        is_ok!(ULID::try_from_parts(largest, u128::MAX)),
+        ULID::from_parts(largest, u128::MAX)
+    );
+    kernel_error_eq!(
+        ULID::try_from_parts(largest + 1, 0),
+        Kind::InvalidInput,
+        Audience::System,
+        "The timestamp of 281474976710656 ms is past the largest ULID timestamp."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that timestamp and day bounds at or past 2^48 ms clamp to the largest timestamp
+/// instead of wrapping to the epoch.
+#[test]
+fn timestamp_bounds_past_range_success() {
+    let past = 1u64 << ULID::TIME_BITS;
+    let largest = ULID::max_for_timestamp(ULID::MAX_TIMESTAMP);
+
+    assert_eq!(ULID::MAX_TIMESTAMP, past - 1);
+    assert_eq!(
+        ULID::min_for_timestamp(past),
+        ULID::min_for_timestamp(ULID::MAX_TIMESTAMP)
+    );
+    assert_eq!(ULID::max_for_timestamp(past), largest);
+    assert_eq!(largest, ULID::from_u128(u128::MAX));
+    assert!(ULID::min_for_timestamp(past) > ULID::max_for_timestamp(0));
+
+    let (start, end) = ULID::range_for_day(past - 1_000);
+    assert_eq!(start, ULID::min_for_timestamp(past - 1_000));
+    assert_eq!(end, largest);
+    assert_eq!(
+        ULID::range_for_day(past),
+        (ULID::min_for_timestamp(past), largest)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that ULIDs minted during a day fall inside that day's range, and
+/// that ULIDs minted at the start of the next day fall outside it.
+#[test]
+fn range_for_day_success() {
+    let day_start: u64 = 1_700_006_400_000;
+    let next_day_start = day_start + 86_400_000;
+    let (start, end) = ULID::range_for_day(day_start);
+
+    assert_eq!(start, ULID::from_parts(day_start, 0));
+    assert_eq!(end, ULID::from_parts(next_day_start - 1, u128::MAX));
+
+    let within_day = [
+        ULID::from_parts(day_start, 0),
+        ULID::from_parts(day_start + 43_200_000, 0x1234_5678_9ABC),
+        ULID::from_parts(next_day_start - 1, u128::MAX),
+    ];
+    for ulid in within_day {
+        assert!(
+            start <= ulid && ulid <= end,
+            "{ulid} should be within the day"
+        );
+    }
+
+    let next_day = ULID::from_parts(next_day_start, 0);
+    assert!(next_day > end);
+    let previous_day = ULID::from_parts(day_start - 1, u128::MAX);
+    assert!(previous_day < start);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that lowercase and mixed-case input is normalized to the canonical
+/// uppercase form.
+#[test]
+fn normalize_string_success() {
+    let normalized = is_ok!(ULID::normalize_string("01d39zy06fgsctvn4t2v9pkhfz"));
+    assert_eq!(normalized, "01D39ZY06FGSCTVN4T2V9PKHFZ");
+
+    let normalized = is_ok!(ULID::normalize_string("01D39zy06FGSCTVN4T2V9PKHFZ"));
+    assert_eq!(normalized, "01D39ZY06FGSCTVN4T2V9PKHFZ");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that invalid input is rejected during normalization.
+#[test]
+fn normalize_string_error() {
+    kernel_error_eq!(
+        ULID::normalize_string("01D39ZY06FGSCTVN4T2V9PKHF!"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKHF!' is not a valid ULID: invalid character."
+    );
+    kernel_error_eq!(
+        ULID::normalize_string("01D39ZY06F"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06F' is not a valid ULID: invalid length."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a namespace sentinel is stable and differs between namespaces.
+#[test]
+fn namespace_min_stable_success() {
+    let procedure = ULID::namespace_min("procedure");
+
+    assert_eq!(procedure, ULID::namespace_min("procedure"));
+    assert_eq!(
+        procedure.to_string(),
+        ULID::namespace_min("procedure").to_string()
+    );
+    assert_ne!(procedure, ULID::namespace_min("report"));
+    assert_ne!(procedure, ULID::namespace_min("Procedure"));
+    assert_ne!(ULID::namespace_min(""), ULID::nil());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that namespace sentinels carry timestamp zero and sort before any minted ULID.
+#[test]
+fn namespace_min_sorts_first_success() {
+    let sentinel = ULID::namespace_min("procedure");
+    let minted = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert_eq!(sentinel.value() >> ULID::RAND_BITS, 0);
+    assert!(sentinel < ULID::min_for_timestamp(1));
+    assert!(sentinel < minted);
+    assert!(sentinel.to_string() < minted.to_string());
+}
+
+fn timestamp(ms: u64) -> UTCTimestamp {
+    is_ok!(UTCTimestamp::builder().use_ms(ms).build())
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that ULIDs minted inside the window or at either bound are within it.
+#[test]
+fn timestamp_within_success() {
+    let (min, max) = (timestamp(1_700_000_000_000), timestamp(1_700_000_060_000));
+
+    let inside = ULID::from_parts(1_700_000_030_000, 42);
+    assert_eq!(inside.timestamp_ms(), 1_700_000_030_000);
+    assert!(inside.timestamp_within(&min, &max));
+    assert!(ULID::min_for_timestamp(1_700_000_000_000).timestamp_within(&min, &max));
+    assert!(ULID::max_for_timestamp(1_700_000_060_000).timestamp_within(&min, &max));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that ULIDs minted just outside the window, or checked against an inverted window,
+/// are not within it.
+#[test]
+fn timestamp_within_outside_success() {
+    let (min, max) = (timestamp(1_700_000_000_000), timestamp(1_700_000_060_000));
+
+    assert!(!ULID::max_for_timestamp(1_699_999_999_999).timestamp_within(&min, &max));
+    assert!(!ULID::min_for_timestamp(1_700_000_060_001).timestamp_within(&min, &max));
+    assert!(!ULID::from_parts(1_700_000_030_000, 0).timestamp_within(&max, &min));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that window bounds past the 48-bit ULID range are compared without wrapping.
+#[test]
+fn timestamp_within_past_range_success() {
+    let past = timestamp(1 << ULID::TIME_BITS);
+    let largest = ULID::max_for_timestamp(ULID::MAX_TIMESTAMP);
+
+    assert!(largest.timestamp_within(&timestamp(0), &past));
+    assert!(!largest.timestamp_within(&past, &timestamp((1 << ULID::TIME_BITS) + 1)));
+    assert!(!ULID::nil().timestamp_within(&past, &past));
+    assert_eq!(ULID::namespace_min("procedure").timestamp_ms(), 0);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the etag is a stable weak tag for the same ULID.
+#[test]
+fn etag_stable_success() {
+    let ulid = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+    let parsed_again = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert_eq!(ulid.etag(), "W/\"94PHJ5JMAK46F\"");
+    assert_eq!(ulid.etag(), parsed_again.etag());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that adjacent ULIDs produce different etags.
+#[test]
+fn etag_differs_success() {
+    let ulid = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+    let next = ulid.increment().unwrap_or_default();
+
+    assert_ne!(ulid.etag(), next.etag());
+    assert_ne!(ULID::nil().etag(), ulid.etag());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the `(msb, lsb)` halves map to the big-endian byte layout, and
+/// that the explicit constructor agrees with the tuple conversion.
+#[test]
+fn u64_pair_layout_success() {
+    let msb: u64 = 0x0123_4567_89AB_CDEF;
+    let lsb: u64 = 0xFEDC_BA98_7654_3210;
+
+    let from_pair = ULID::from_u64_pair(msb, lsb);
+    let from_tuple = ULID::from((msb, lsb));
+
+    assert_eq!(from_pair.to_bytes(), from_tuple.to_bytes());
+    assert_eq!(from_pair.to_bytes()[..8], msb.to_be_bytes());
+    assert_eq!(from_pair.to_bytes()[8..], lsb.to_be_bytes());
+    assert_eq!(from_pair.to_u64_pair(), (msb, lsb));
+    assert_eq!(<(u64, u64)>::from(from_pair), (msb, lsb));
+    assert_eq!(
+        ULID::from_bytes(from_pair.to_bytes()).to_u64_pair(),
+        (msb, lsb)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a ULID compares equal to its canonical and lowercase encodings.
+#[test]
+fn eq_str_equal_success() {
+    let ulid = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert!(ulid.eq_str("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+    assert!(ulid.eq_str("01d39zy06fgsctvn4t2v9pkhfz"));
+    assert_eq!(ULID::from_u128(ulid.value()), ulid);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a ULID does not compare equal to a different or malformed string.
+#[test]
+fn eq_str_unequal_error() {
+    let ulid = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert!(!ulid.eq_str("01D39ZY06FGSCTVN4T2V9PKHG0"));
+    assert!(!ulid.eq_str("01D39ZY06FGSCTVN4T2V9PKHF"));
+    assert!(!ulid.eq_str("01D39ZY06FGSCTVN4T2V9PKHF!"));
+    assert!(!ulid.eq_str(""));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that batch decoding returns one result per input, in input order.
+#[test]
+fn decode_batch_mixed_success() {
+    let results = ULID::decode_batch(&[
+        "01D39ZY06FGSCTVN4T2V9PKHFZ",
+        "01D39ZY06FGSCTVN4T2V9PKHF",
+        "01d39zy06fgsctvn4t2v9pkhg0",
+        "01D39ZY06FGSCTVN4T2V9PKHF!",
+    ]);
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(
+        is_ok!(&results[0]).to_string(),
+        "01D39ZY06FGSCTVN4T2V9PKHFZ"
+    );
+    kernel_error_eq!(
+        &results[1],
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKHF' is not a valid ULID: invalid length."
+    );
+    assert_eq!(
+        is_ok!(&results[2]).to_string(),
+        "01D39ZY06FGSCTVN4T2V9PKHG0"
+    );
+    kernel_error_eq!(
+        &results[3],
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKHF!' is not a valid ULID: invalid character."
+    );
+    assert!(ULID::decode_batch(&[]).is_empty());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a URL component decodes a plain ULID and one with percent-encoded characters to
+/// the same value.
+#[test]
+fn from_url_component_success() {
+    let expected = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    assert_eq!(
+        is_ok!(ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKHFZ")),
+        expected
+    );
+    assert_eq!(
+        is_ok!(ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKH%46Z")),
+        expected
+    );
+    assert_eq!(
+        is_ok!(ULID::from_url_component("%30%31d39zy06fgsctvn4t2v9pkh%66z")),
+        expected
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that malformed percent escapes and decoded text that is not a ULID are rejected.
+#[test]
+fn from_url_component_error() {
+    kernel_error_eq!(
+        ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKH%4"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKH%4' has a malformed percent-encoded sequence at byte 24."
+    );
+    kernel_error_eq!(
+        ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKH%ZZZ"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKH%ZZZ' has a malformed percent-encoded sequence at byte 24."
+    );
+    kernel_error_eq!(
+        ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKH%+6Z"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKH%+6Z' has a malformed percent-encoded sequence at byte 24."
+    );
+    kernel_error_eq!(
+        ULID::from_url_component("01D39ZY06FGSCTVN4T2V9PKH%21Z"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '01D39ZY06FGSCTVN4T2V9PKH%21Z' is not a valid ULID: invalid character."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that each decode error variant converts to the expected kernel error.
+#[test]
+fn decode_error_into_error_success() {
+    assert_eq!(
+        Error::from(DecodeError::InvalidLength),
+        Error::for_user(
+            Kind::InvalidInput,
+            "A ULID must be exactly 26 characters long."
+        )
+    );
+    assert_eq!(
+        Error::from(DecodeError::InvalidChar),
+        Error::for_user(
+            Kind::InvalidInput,
+            "A ULID may only contain Crockford Base32 characters: 0-9 and A-Z except I, L, O, and U."
+        )
+    );
+    assert_eq!(
+        Error::from(PrefixError::MissingPrefix),
+        Error::for_user(
+            Kind::InvalidInput,
+            "A prefixed ULID must have the form 'prefix_ULID' with a non-empty prefix."
+        )
+    );
+    assert_eq!(
+        Error::from(AlphabetError::InvalidAlphabet),
+        Error::for_system(
+            Kind::InvalidInput,
+            "A custom base32 alphabet must contain 32 distinct ASCII characters."
+        )
+    );
+    assert_eq!(
+        Error::from(PrefixError::Decode(DecodeError::InvalidChar)),
+        Error::from(DecodeError::InvalidChar)
+    );
+    assert_eq!(
+        Error::from(AlphabetError::Decode(DecodeError::InvalidLength)),
+        Error::from(DecodeError::InvalidLength)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that `?` converts a decode error inside a function returning the kernel `Result`.
+#[test]
+fn decode_error_question_mark_error() {
+    fn parse(encoded: &str) -> Result<ULID, Error> {
+        Ok(ULID::from_string(encoded)?)
+    }
+
+    assert_eq!(
+        is_ok!(parse("01D39ZY06FGSCTVN4T2V9PKHFZ")),
+        is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"))
+    );
+    kernel_error_eq!(
+        parse("01D39ZY06F"),
+        Kind::InvalidInput,
+        Audience::User,
+        "A ULID must be exactly 26 characters long."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a prefixed identifier round-trips and that the split uses the last separator.
+#[test]
+fn prefixed_round_trip_success() {
+    let ulid = is_ok!(ULID::from_string("01D39ZY06FGSCTVN4T2V9PKHFZ"));
+
+    let prefixed = ulid.to_prefixed("usr");
+    assert_eq!(prefixed, "usr_01D39ZY06FGSCTVN4T2V9PKHFZ");
+    assert_eq!(
+        is_ok!(ULID::from_prefixed(&prefixed)),
+        ("usr".to_string(), ulid)
+    );
+
+    assert_eq!(
+        is_ok!(ULID::from_prefixed(&ulid.to_prefixed("org_team"))),
+        ("org_team".to_string(), ulid)
+    );
+    assert_eq!(
+        is_ok!(ULID::from_prefixed("usr_01d39zy06fgsctvn4t2v9pkhfz")),
+        ("usr".to_string(), ulid)
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that identifiers without a separator or prefix, or with a bad ULID part, are
+/// rejected.
+#[test]
+fn from_prefixed_error() {
+    assert_eq!(
+        ULID::from_prefixed("01D39ZY06FGSCTVN4T2V9PKHFZ"),
+        Err(PrefixError::MissingPrefix)
+    );
+    assert_eq!(
+        ULID::from_prefixed("_01D39ZY06FGSCTVN4T2V9PKHFZ"),
+        Err(PrefixError::MissingPrefix)
+    );
+    assert_eq!(
+        ULID::from_prefixed("usr_01D39ZY06F"),
+        Err(PrefixError::Decode(DecodeError::InvalidLength))
+    );
+    assert_eq!(
+        ULID::from_prefixed("usr_01D39ZY06FGSCTVN4T2V9PKHFU"),
+        Err(PrefixError::Decode(DecodeError::InvalidChar))
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that shard indexes stay within bounds, depend only on the random bits, and spread
+/// sequential identities evenly.
+#[test]
+fn shard_within_bounds_success() {
+    let mut counts = [0u32; 7];
+    for random in 0..700u128 {
+        let shard = ULID::from_parts(1_700_000_000_000, random).shard(7);
+        assert!(shard < 7);
+        counts[usize::from(shard)] += 1;
+    }
+    assert_eq!(counts, [100; 7]);
+
+    let early = ULID::from_parts(1_000, 0xABCD_EF01);
+    let late = ULID::from_parts(1_700_000_000_000, 0xABCD_EF01);
+    assert_eq!(early.shard(1_024), late.shard(1_024));
+    // The random bits of the maximum ULID are 2^80 - 1 = ...629_174_706_175.
+    assert_eq!(ULID::from_u128(u128::MAX).shard(1_000), 175);
+    assert_eq!(ULID::from_u128(u128::MAX).shard(1), 0);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a zero shard count returns shard zero instead of panicking.
+#[test]
+fn shard_zero_count_success() {
+    assert_eq!(ULID::from_parts(1_700_000_000_000, 42).shard(0), 0);
+    assert_eq!(ULID::from_u128(u128::MAX).shard(0), 0);
 }
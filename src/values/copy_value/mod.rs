@@ -0,0 +1,26 @@
+use crate::error::Error;
+
+/// A value type that can produce a validated copy of itself.
+pub trait CopyValue {
+    /// Returns a copied value or a validation error.
+    fn copy(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Returns a copied value with `mutate` applied to the copy.
+    ///
+    /// Use this for value objects that need a derived duplicate, such as a copy with one field
+    /// replaced. The original value is left unchanged, and `mutate` is not called when
+    /// [`CopyValue::copy`] fails.
+    fn copy_with<F: FnOnce(&mut Self)>(&self, mutate: F) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut copied = self.copy()?;
+        mutate(&mut copied);
+        Ok(copied)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+//! Verifies the default behavior of the copy-value role trait.
+//!
+//! Bounded unit under test:
+//! - `CopyValue`
+//!
+//! Public interfaces verified:
+//! - `CopyValue::copy_with`
+//!
+//! Logical paths covered:
+//! - the mutation is applied to the copy and not to the original
+//! - a failed copy is returned without applying the mutation
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::CopyValue;
+use crate::error::{Audience, Error, Kind};
+use test_framework_oss::{is_ok, kernel_error_eq};
+
+#[derive(Debug, PartialEq)]
+struct Counter {
+    count: u32,
+}
+
+impl CopyValue for Counter {
+    fn copy(&self) -> Result<Self, Error> {
+        if self.count == u32::MAX {
+            return Err(Error::for_system(
+                Kind::ExceedsMax,
+                "The counter cannot be copied at its maximum.".to_string(),
+            ));
+        }
+        Ok(Counter { count: self.count })
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the mutation is applied to the copy and not to the original.
+#[test]
+fn copy_with_override_success() {
+    let original = Counter { count: 1 };
+
+    let copied = is_ok!(original.copy_with(|counter| counter.count = 2));
+
+    assert_eq!(copied, Counter { count: 2 });
+    assert_eq!(original, Counter { count: 1 });
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a failed copy is returned without applying the mutation.
+#[test]
+fn copy_with_failed_copy_error() {
+    let original = Counter { count: u32::MAX };
+    let mut mutated = false;
+
+    let result = original.copy_with(|_| mutated = true);
+
+    kernel_error_eq!(
+        result,
+        Kind::ExceedsMax,
+        Audience::System,
+        "The counter cannot be copied at its maximum."
+    );
+    assert!(!mutated);
+}
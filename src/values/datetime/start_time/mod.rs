@@ -1,5 +1,7 @@
 use crate::error::{Error, Kind};
+use crate::values::datetime::utc_timestamp::UTCTimestamp;
 use std::fmt::{Display, Formatter};
+use std::sync::{Mutex, PoisonError};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(test)]
@@ -66,6 +68,13 @@ impl StartTime {
         }
         Ok(Self { time })
     }
+
+    /// Converts the start time into a nanosecond [`UTCTimestamp`].
+    ///
+    /// Start times past `u128::MAX` nanoseconds saturate at `u128::MAX`.
+    pub fn to_utc_timestamp(&self) -> UTCTimestamp {
+        UTCTimestamp::from_nanos(self.time.saturating_mul(1_000_000))
+    }
 }
 
 impl Display for StartTime {
@@ -74,6 +83,56 @@ impl Display for StartTime {
     }
 }
 
+/// Issues [`StartTime`] values that never decrease, even if the wall clock moves backward.
+///
+/// Each call to [`MonotonicStartTime::now`] returns the later of the clock reading and one
+/// millisecond after the previously issued value, so consecutive values are strictly increasing.
+/// The clock defaults to the system wall clock and can be replaced with
+/// [`MonotonicStartTime::with_source`].
+pub struct MonotonicStartTime<F = fn() -> u128> {
+    /// Millisecond clock readings.
+    source: F,
+    /// The most recently issued milliseconds, or `None` before the first call.
+    last: Mutex<Option<u128>>,
+}
+
+impl MonotonicStartTime {
+    /// Creates a monotonic start time backed by the system wall clock.
+    pub fn new() -> Self {
+        MonotonicStartTime::with_source(get_current_time_in_milliseconds)
+    }
+}
+
+impl Default for MonotonicStartTime {
+    fn default() -> Self {
+        MonotonicStartTime::new()
+    }
+}
+
+impl<F: Fn() -> u128> MonotonicStartTime<F> {
+    /// Creates a monotonic start time that reads milliseconds from `source`.
+    pub fn with_source(source: F) -> Self {
+        MonotonicStartTime {
+            source,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Returns the current time, never earlier than one millisecond after the previous call.
+    pub fn now(&self) -> StartTime {
+        // A poisoned lock only means another caller panicked mid-update; the slot still holds
+        // the last issued value, so continuing from it keeps the sequence ordered.
+        let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        let reading = (self.source)();
+        let time = match *last {
+            Some(previous) => reading.max(previous.saturating_add(1)),
+            None => reading,
+        };
+        *last = Some(time);
+        StartTime { time }
+    }
+}
+
 fn get_current_time_in_milliseconds() -> u128 {
     let now = SystemTime::now();
     let since_the_epoch = now.duration_since(UNIX_EPOCH).expect("Time went backwards");
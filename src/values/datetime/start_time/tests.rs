@@ -2,14 +2,17 @@
 //!
 //! Bounded unit under test: `StartTime`.
 //! Public interfaces verified: `now`, `from`, `try_from`, and `to_string`.
-//! Public interfaces verified: `time` accessor.
-//! Logical paths covered: current time capture, explicit construction, successful parsing, and the
-//! zero-value rejection path.
+//! Public interfaces verified: `time` accessor and `to_utc_timestamp`.
+//! Public interfaces verified: `MonotonicStartTime::new`, `with_source`, and `now`.
+//! Logical paths covered: current time capture, explicit construction, successful parsing, the
+//! zero-value rejection path, and round trips through `UTCTimestamp` including saturation, and monotonic
+//! readings across a backward clock.
 //! Requirement validation points: standards-aligned behavior for the start-time value object.
 
 use crate::error::Audience;
 use crate::error::Kind;
-use crate::values::datetime::start_time::StartTime;
+use crate::values::datetime::start_time::{MonotonicStartTime, StartTime};
+use std::cell::Cell;
 use test_framework_oss::{is_error, is_ok};
 
 #[test]
@@ -58,3 +61,52 @@ fn try_from_zero_error() {
         "The start time cannot be 0.  Please provide a valid utc time."
     );
 }
+
+#[test]
+/// Requirement validation: verifies representative start times round-trip through `UTCTimestamp`.
+fn utc_timestamp_round_trip_success() {
+    for time in [0u128, 1, 1_739_450_096_789, u128::from(u64::MAX)] {
+        let timestamp = StartTime::from(time).to_utc_timestamp();
+        assert_eq!(timestamp.as_nano(), time * 1_000_000);
+        assert_eq!(timestamp.to_start_time(), StartTime::from(time));
+    }
+}
+
+#[test]
+/// Requirement validation: verifies start times past the nanosecond range saturate at
+/// `u128::MAX` nanoseconds.
+fn utc_timestamp_saturates_success() {
+    let timestamp = StartTime::from(u128::MAX / 1_000_000 + 1).to_utc_timestamp();
+    assert_eq!(timestamp.as_nano(), u128::MAX);
+    assert_eq!(
+        timestamp.to_start_time(),
+        StartTime::from(u128::MAX / 1_000_000)
+    );
+}
+
+#[test]
+/// Requirement validation: verifies `MonotonicStartTime` never decreases when the clock moves
+/// backward and follows the clock again once it passes the last value.
+fn monotonic_backward_clock_success() {
+    let readings = [1_000u128, 1_005, 990, 990, 1_003, 1_020];
+    let index = Cell::new(0);
+    let clock = MonotonicStartTime::with_source(|| {
+        let reading = readings[index.get()];
+        index.set(index.get() + 1);
+        reading
+    });
+
+    let times: Vec<u128> = readings.iter().map(|_| clock.now().time()).collect();
+
+    assert_eq!(times, [1_000, 1_005, 1_006, 1_007, 1_008, 1_020]);
+    assert!(times.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+/// Requirement validation: verifies the system-backed `MonotonicStartTime` increases across calls.
+fn monotonic_system_clock_success() {
+    let clock = MonotonicStartTime::new();
+    let first = clock.now();
+    let second = clock.now();
+    assert!(second > first);
+}
@@ -1,7 +1,10 @@
 //! UTC timestamp bounded value.
 
 use crate::error::Error;
-use crate::error::Kind::InvalidInput;
+use crate::error::Kind::{ExceedsMax, InvalidInput};
+use crate::values::datetime::start_time::StartTime;
+use std::cmp::Ordering;
+use std::time::Duration;
 
 #[cfg(test)]
 mod tests;
@@ -11,11 +14,18 @@ mod tests;
 /// - Builder setters:
 ///   - `use_ns(ns: u128)` — provide a nanosecond timestamp (last setter wins).
 ///   - `use_ms(ms: u64)` — provide a millisecond timestamp (last setter wins).
-///   - `build() -> Result<UTCTimestamp, Error>` — constructs the value or returns an error if no value was provided.
+///   - `use_duration(d: Duration)` — provide a duration since the epoch as nanoseconds (last setter wins).
+///   - `reject_before(ms: u64)` / `reject_after(ms: u64)` — optional inclusive plausibility range.
+///   - `build() -> Result<UTCTimestamp, Error>` — constructs the value or returns an error if no value was provided or it falls outside the configured range.
 /// - Accessors:
 ///   - `as_nano() -> u128` — nanosecond view (exact stored value).
 ///   - `as_milli() -> u64` — millisecond view (bounded to `u64`).
 ///   - `as_sec() -> u64` — second view (cast/truncated to `u64`).
+///   - `as_duration() -> Duration` — duration view (capped at `Duration::MAX`).
+///   - `to_start_time() -> StartTime` — millisecond start time (sub-millisecond nanoseconds truncated).
+///   - `to_start_time_rounded() -> Result<StartTime, Error>` — millisecond start time rounded half up.
+///   - `to_compact() -> String` — sortable, file-name-safe `YYYYMMDDTHHMMSSmmm` rendering.
+///   - `iso_week() -> (i32, u8)` — ISO-8601 week-numbering year and week (1–53).
 ///
 /// ## Examples
 /// ```rust
@@ -47,14 +57,17 @@ mod tests;
 /// ## Overflow, capping, and casting behavior (documented)
 /// - `as_milli()` returns a `u64`. If the computed milliseconds exceed `u64::MAX`, the value is capped to `u64::MAX` to present a bounded millisecond view.
 /// - `as_sec()` returns a `u64` computed from the integer division of nanoseconds by `1_000_000_000` and then cast to `u64`. If the computed seconds exceed `u64::MAX`, the cast will truncate/wrap as per Rust's `as` semantics for integer casts; this wrap/truncate behavior is intentional and covered by tests.
+/// - `as_sec_saturating()` returns the same seconds, but caps at `u64::MAX` like `as_milli()` instead of wrapping.
+/// - `as_duration()` returns `Duration::MAX` when the whole seconds exceed `u64::MAX`; below that it is exact, so `use_duration(d)` round-trips.
 /// - Building from `u64::MAX` milliseconds yields a valid `u128` nanosecond value (`ms * 1_000_000u128`) without arithmetic overflow, and `as_milli()` will equal `u64::MAX`.
 ///
 /// ## Error behavior
 /// - Calling `build()` without setting either `use_ns(...)` or `use_ms(...)` returns an `Err(Error)` with `Kind::InvalidInput` and system audience. The exact error message returned is:
 ///   `A value was not provided for the DateTime, please provide a valid DateTime value.`
+/// - When `reject_before(...)` or `reject_after(...)` is set and the value falls outside that range, `build()` returns `Kind::InvalidInput` for the system audience, naming the value and the violated bound in milliseconds.
 ///
 /// ## Notes for callers
-/// - If callers require saturation semantics for seconds (instead of the documented cast/wrap), they must validate or normalize input before constructing a `UTCTimestamp`.
+/// - If callers require saturation semantics for seconds (instead of the documented cast/wrap), use `as_sec_saturating()`.
 /// - The builder enforces a last-setter-wins rule; call order determines the stored value.
 /// - Unit tests exist to validate edge cases: `u64::MAX` milliseconds, very large `ns` that trigger millisecond capping, `ns` values that cause second cast/wrap, sub-millisecond inputs, and truncation semantics.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -69,12 +82,19 @@ impl UTCTimestamp {
         UTCTimestampBuilder::default()
     }
 
+    /// Wraps a nanosecond value that is already known to be valid.
+    pub(crate) fn from_nanos(timestamp: u128) -> Self {
+        UTCTimestamp { timestamp }
+    }
+
     /// Returns the timestamp as nanoseconds.
     pub fn as_nano(&self) -> u128 {
         self.timestamp
     }
 
-    /// Returns the timestamp as milliseconds.
+    /// Returns the timestamp as milliseconds, rounded down and capped at `u64::MAX`.
+    ///
+    /// Use [`UTCTimestamp::as_milli_ceil`] for deadlines, where rounding down would expire early.
     pub fn as_milli(&self) -> u64 {
         let millis = self.timestamp / 1_000_000;
         if millis > u64::MAX as u128 {
@@ -84,37 +104,309 @@ impl UTCTimestamp {
         }
     }
 
+    /// Returns the timestamp as milliseconds, rounded up and capped at `u64::MAX`.
+    ///
+    /// A timestamp on a whole millisecond gives the same value as [`UTCTimestamp::as_milli`]; any
+    /// sub-millisecond remainder adds one. Use this for expiry times, so a deadline of
+    /// `1_000.5` ms is not reported as the already-passed `1_000` ms.
+    pub fn as_milli_ceil(&self) -> u64 {
+        u64::try_from(self.timestamp.div_ceil(1_000_000)).unwrap_or(u64::MAX)
+    }
+
     /// Returns the timestamp as seconds.
     pub fn as_sec(&self) -> u64 {
         (self.timestamp / 1_000_000_000) as u64
     }
+
+    /// Returns the timestamp as seconds, capped at `u64::MAX` instead of wrapping like [`UTCTimestamp::as_sec`].
+    pub fn as_sec_saturating(&self) -> u64 {
+        u64::try_from(self.timestamp / 1_000_000_000).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the timestamp as a [`Duration`] since the Unix epoch, capped at [`Duration::MAX`].
+    pub fn as_duration(&self) -> Duration {
+        match u64::try_from(self.timestamp / 1_000_000_000) {
+            Ok(secs) => Duration::new(secs, self.subsec_nanos()),
+            Err(_) => Duration::MAX,
+        }
+    }
+
+    /// Converts the timestamp into a millisecond [`StartTime`].
+    ///
+    /// Nanoseconds below a whole millisecond are truncated, so converting back through
+    /// [`StartTime::to_utc_timestamp`] only round-trips timestamps on a millisecond boundary.
+    pub fn to_start_time(&self) -> StartTime {
+        StartTime::from(self.timestamp / 1_000_000)
+    }
+
+    /// Compares the timestamp with a millisecond [`StartTime`] at millisecond resolution.
+    ///
+    /// Nanoseconds below a whole millisecond are ignored, so `1_000_999` ns compares equal to
+    /// `1` ms. Unlike [`UTCTimestamp::as_milli`], the comparison is not capped at `u64::MAX`.
+    pub fn cmp_start_time(&self, start_time: &StartTime) -> Ordering {
+        (self.timestamp / 1_000_000).cmp(&start_time.time)
+    }
+
+    /// Converts the timestamp into a millisecond [`StartTime`], rounding half a millisecond up.
+    ///
+    /// Use [`UTCTimestamp::to_start_time`] when truncation is wanted instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns a system-facing [`InvalidInput`] error when rounding up would overflow the
+    /// nanosecond range.
+    pub fn to_start_time_rounded(&self) -> Result<StartTime, Error> {
+        let rounded = self.timestamp.checked_add(500_000).ok_or_else(|| {
+            Error::for_system(
+                InvalidInput,
+                format!(
+                    "The UTCTimestamp {} ns cannot be rounded to milliseconds without overflowing.",
+                    self.timestamp
+                ),
+            )
+        })?;
+        Ok(StartTime::from(rounded / 1_000_000))
+    }
+
+    /// Returns the nanoseconds within the current second, from `0` to `999_999_999`.
+    pub fn subsec_nanos(&self) -> u32 {
+        (self.timestamp % 1_000_000_000) as u32
+    }
+
+    /// Renders the UTC time as `YYYYMMDDTHHMMSSmmm`, truncated to millisecond resolution.
+    ///
+    /// The output contains only digits and `T`, so it is a valid
+    /// [`FileName`](crate::values::file_system::file_name::FileName), and it sorts
+    /// lexicographically in time order for years 0 through 9999. Later years widen the year field.
+    ///
+    /// ```rust
+    /// use kernel_oss::values::datetime::utc_timestamp::UTCTimestamp;
+    ///
+    /// let ts = UTCTimestamp::builder().use_ms(1_739_450_096_789).build().unwrap();
+    /// assert_eq!(ts.to_compact(), "20250213T123456789");
+    /// ```
+    pub fn to_compact(&self) -> String {
+        let days = i64::try_from(self.timestamp / NANOS_PER_DAY)
+            .unwrap_or(i64::MAX)
+            .min(MAX_DAYS);
+        let (year, month, day) = civil_from_days(days);
+        let millis_of_day = (self.timestamp % NANOS_PER_DAY) / 1_000_000;
+        format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}{:03}",
+            year,
+            month,
+            day,
+            millis_of_day / 3_600_000,
+            millis_of_day / 60_000 % 60,
+            millis_of_day / 1_000 % 60,
+            millis_of_day % 1_000
+        )
+    }
+
+    /// Returns the ISO-8601 week-numbering year and week number (1–53) of the UTC day.
+    ///
+    /// Weeks start on Monday, and week 1 is the week containing the year's first Thursday, so the
+    /// ISO year differs from the calendar year for some days near January 1st. Years past
+    /// `i32::MAX` are capped.
+    ///
+    /// ```rust
+    /// use kernel_oss::values::datetime::utc_timestamp::UTCTimestamp;
+    ///
+    /// // 2021-01-01 falls in the last week of ISO year 2020.
+    /// let ts = UTCTimestamp::builder().use_ms(1_609_459_200_000).build().unwrap();
+    /// assert_eq!(ts.iso_week(), (2020, 53));
+    /// ```
+    pub fn iso_week(&self) -> (i32, u8) {
+        let days = i64::try_from(self.timestamp / NANOS_PER_DAY)
+            .unwrap_or(i64::MAX)
+            .min(MAX_DAYS);
+        // 1970-01-01 was a Thursday; weekday is 1 (Monday) through 7 (Sunday).
+        let weekday = (days + 3).rem_euclid(7) + 1;
+        let thursday = days - weekday + 4;
+        let (year, day_of_year) = year_and_ordinal_from_days(thursday);
+        let week = (day_of_year - 1) / 7 + 1;
+        (i32::try_from(year).unwrap_or(i32::MAX), week as u8)
+    }
+
+    /// Returns the earliest timestamp in `timestamps`, or `None` when the slice is empty.
+    pub fn min_of(timestamps: &[UTCTimestamp]) -> Option<UTCTimestamp> {
+        timestamps.iter().min().copied()
+    }
+
+    /// Returns the latest timestamp in `timestamps`, or `None` when the slice is empty.
+    pub fn max_of(timestamps: &[UTCTimestamp]) -> Option<UTCTimestamp> {
+        timestamps.iter().max().copied()
+    }
+}
+
+const NANOS_PER_DAY: u128 = 86_400_000_000_000;
+
+/// Keeps day arithmetic well inside `i64` for timestamps far beyond any calendar in use.
+const MAX_DAYS: i64 = i64::MAX / 4;
+
+/// Converts days since the Unix epoch into the proleptic Gregorian year, month (1–12), and day of month (1–31).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of each 400-year era.
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_march_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let march_month = (5 * day_of_march_year + 2) / 153;
+    let day = day_of_march_year - (153 * march_month + 2) / 5 + 1;
+    let month = if march_month < 10 {
+        march_month + 3
+    } else {
+        march_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts days since the Unix epoch into the proleptic Gregorian year and the 1-based day of that year.
+fn year_and_ordinal_from_days(days: i64) -> (i64, i64) {
+    const DAYS_BEFORE_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+    let (year, month, day) = civil_from_days(days);
+    let is_leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let leap_day = i64::from(is_leap && month > 2);
+    (
+        year,
+        DAYS_BEFORE_MONTH[(month - 1) as usize] + leap_day + day,
+    )
 }
 
 /// Builds a [`UTCTimestamp`].
+///
+/// The value setters ([`use_secs`](UTCTimestampBuilder::use_secs),
+/// [`use_ms`](UTCTimestampBuilder::use_ms), [`use_ms_checked`](UTCTimestampBuilder::use_ms_checked),
+/// [`use_ns`](UTCTimestampBuilder::use_ns), and [`use_duration`](UTCTimestampBuilder::use_duration))
+/// all write the same nanosecond value, so the last setter called wins.
 #[derive(Debug, Clone, Default)]
 pub struct UTCTimestampBuilder {
     /// Raw timestamp input in nanoseconds.
     timestamp: Option<u128>,
+    /// Earliest accepted timestamp in nanoseconds, inclusive.
+    earliest: Option<u128>,
+    /// Latest accepted timestamp in nanoseconds, inclusive.
+    latest: Option<u128>,
 }
 
 impl UTCTimestampBuilder {
-    /// Provide a 64-bit millisecond value representing seconds since the Unix epoch.
+    /// Provide a 64-bit second value representing seconds since the Unix epoch.
+    ///
+    /// Every `u64` second value fits in nanoseconds, so this cannot overflow.
+    pub fn use_secs(mut self, timestamp: u64) -> Self {
+        // u64::MAX * 1_000_000_000 is far below u128::MAX.
+        self.timestamp = Some(u128::from(timestamp) * 1_000_000_000);
+        self
+    }
+
+    /// Provide a 64-bit millisecond value representing milliseconds since the Unix epoch.
+    ///
+    /// Every `u64` millisecond value fits in nanoseconds, so this cannot overflow. Use
+    /// [`UTCTimestampBuilder::use_ms_checked`] for wider millisecond values, such as a
+    /// [`StartTime`].
     pub fn use_ms(mut self, timestamp: u64) -> Self {
-        let nanos = (timestamp as u128).saturating_mul(1_000_000u128);
-        self.timestamp = Some(nanos);
+        // u64::MAX * 1_000_000 is far below u128::MAX.
+        self.timestamp = Some(u128::from(timestamp) * 1_000_000);
         self
     }
 
+    /// Provide a millisecond value of up to 128 bits, rejecting values too large to store in
+    /// nanoseconds instead of clamping them.
+    ///
+    /// Accepts `u64` values exactly as [`UTCTimestampBuilder::use_ms`] does; only `u128` values
+    /// above `u128::MAX / 1_000_000` can fail.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::ExceedsMax`](crate::error::Kind::ExceedsMax) for
+    /// [`Audience::System`](crate::error::Audience::System) is returned when the value in
+    /// nanoseconds would overflow a `u128`.
+    pub fn use_ms_checked(mut self, timestamp: impl Into<u128>) -> Result<Self, Error> {
+        let timestamp = timestamp.into();
+        let nanos = timestamp.checked_mul(1_000_000).ok_or_else(|| {
+            Error::for_system(
+                ExceedsMax,
+                format!(
+                    "The UTCTimestamp {} ms is too large to store in nanoseconds.",
+                    timestamp
+                ),
+            )
+        })?;
+        self.timestamp = Some(nanos);
+        Ok(self)
+    }
+
     /// Provide a full 128-bit nanosecond value representing nanoseconds since the Unix epoch.
     pub fn use_ns(mut self, timestamp: u128) -> Self {
         self.timestamp = Some(timestamp);
         self
     }
 
+    /// Provide a [`Duration`] since the Unix epoch, stored at nanosecond precision.
+    pub fn use_duration(mut self, duration: Duration) -> Self {
+        self.timestamp = Some(duration.as_nanos());
+        self
+    }
+
+    /// Reject timestamps earlier than `ms` milliseconds since the Unix epoch.
+    ///
+    /// Use with [`UTCTimestampBuilder::reject_after`] to bound the plausible range, which catches
+    /// callers passing seconds into a millisecond setter, or microseconds, by mistake.
+    pub fn reject_before(mut self, ms: u64) -> Self {
+        self.earliest = Some(u128::from(ms) * 1_000_000);
+        self
+    }
+
+    /// Reject timestamps later than `ms` milliseconds since the Unix epoch.
+    ///
+    /// The bound is inclusive at millisecond resolution, so any nanoseconds within `ms` are
+    /// accepted.
+    pub fn reject_after(mut self, ms: u64) -> Self {
+        self.latest = Some(u128::from(ms) * 1_000_000 + 999_999);
+        self
+    }
+
     /// Validates the builder and creates a timestamp value.
     pub fn build(self) -> Result<UTCTimestamp, Error> {
-        validate_value(self.timestamp)
+        let timestamp = validate_value(self.timestamp)?;
+        validate_range(timestamp, self.earliest, self.latest)
+    }
+}
+
+fn validate_range(
+    timestamp: UTCTimestamp,
+    earliest: Option<u128>,
+    latest: Option<u128>,
+) -> Result<UTCTimestamp, Error> {
+    if let Some(earliest) = earliest
+        && timestamp.timestamp < earliest
+    {
+        return Err(Error::for_system(
+            InvalidInput,
+            format!(
+                "The UTCTimestamp {} ms is before the earliest accepted value of {} ms. Check that the value is in milliseconds.",
+                timestamp.as_milli(),
+                earliest / 1_000_000
+            ),
+        ));
+    }
+    if let Some(latest) = latest
+        && timestamp.timestamp > latest
+    {
+        return Err(Error::for_system(
+            InvalidInput,
+            format!(
+                "The UTCTimestamp {} ms is after the latest accepted value of {} ms. Check that the value is in milliseconds.",
+                timestamp.as_milli(),
+                latest / 1_000_000
+            ),
+        ));
     }
+    Ok(timestamp)
 }
 
 fn validate_value(value: Option<u128>) -> Result<UTCTimestamp, Error> {
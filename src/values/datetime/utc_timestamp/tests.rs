@@ -1,14 +1,20 @@
 //! Tests for `UTCTimestamp`, covering builder inputs, conversion behavior, and failure cases.
 //!
 //! Bounded unit under test: `UTCTimestamp`.
-//! Public interfaces verified: the builder, `as_nano`, `as_milli`, `as_sec`, and error handling.
-//! Logical paths covered: millisecond input, nanosecond input, setter override behavior, overflow
-//! handling, truncation behavior, zero-input rejection, and sub-millisecond conversion.
+//! Public interfaces verified: the builder including its `reject_before`/`reject_after` range guard, `use_secs`, `use_ms_checked`, `as_nano`, `as_milli`, `as_milli_ceil`, `as_sec`, `as_sec_saturating`,
+//! `subsec_nanos`, `as_duration`, `to_compact`, `to_start_time`, `to_start_time_rounded`, `cmp_start_time`, `iso_week`, `min_of`, `max_of`, and error handling.
+//! Logical paths covered: second input, millisecond input, nanosecond input, setter override behavior across every setter, overflow
+//! handling, checked millisecond input with overflow rejection, saturating seconds, truncation behavior, ceiling milliseconds at exact multiples, remainders, and the cap, zero-input rejection, plausible-range rejection below and above the bounds, sub-millisecond
+//! conversion, sub-second nanoseconds, duration input and output including saturation, compact file-name rendering, start-time truncation and half-up rounding with overflow rejection, millisecond-resolution comparison against a start time, ISO week numbering including year boundaries, and min/max selection over populated and empty slices.
 //! Requirement validation points: standards-aligned timestamp conversion and validation behavior.
 
 use crate::error::Audience;
 use crate::error::Kind;
+use crate::values::datetime::start_time::StartTime;
 use crate::values::datetime::utc_timestamp::UTCTimestamp;
+use crate::values::file_system::file_name::FileName;
+use std::cmp::Ordering;
+use std::time::Duration;
 use test_framework_oss::is_error;
 use test_framework_oss::is_ok;
 use test_framework_oss::kernel_error_eq;
@@ -49,6 +55,84 @@ fn builder_override_last_call_success() {
 }
 
 #[test]
+/// Requirement validation: verifies second input converts to the expected millisecond and nanosecond views.
+fn from_secs_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_secs(1_700_000_000).build());
+    assert_eq!(ts.as_milli(), 1_700_000_000_000u64);
+    assert_eq!(ts.as_nano(), 1_700_000_000_000_000_000u128);
+    assert_eq!(ts.as_sec(), 1_700_000_000u64);
+
+    let max = is_ok!(UTCTimestamp::builder().use_secs(u64::MAX).build());
+    assert_eq!(max.as_nano(), u128::from(u64::MAX) * 1_000_000_000);
+    assert_eq!(max.as_sec(), u64::MAX);
+}
+
+#[test]
+/// Requirement validation: verifies the last setter wins whichever unit each setter uses.
+fn builder_override_across_units_success() {
+    let secs_last = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(1_234_567_891)
+            .use_ms(5_000)
+            .use_secs(7)
+            .build()
+    );
+    assert_eq!(secs_last.as_nano(), 7_000_000_000);
+
+    let ns_last = is_ok!(
+        UTCTimestamp::builder()
+            .use_secs(7)
+            .use_ns(1_234_567_891)
+            .build()
+    );
+    assert_eq!(ns_last.as_nano(), 1_234_567_891);
+    assert_eq!(ns_last.as_milli(), 1_234);
+
+    let duration_last = is_ok!(
+        UTCTimestamp::builder()
+            .use_secs(7)
+            .use_duration(Duration::from_millis(9))
+            .build()
+    );
+    assert_eq!(duration_last.as_nano(), 9_000_000);
+}
+
+#[test]
+/// Requirement validation: verifies the ceiling millisecond view matches the floor on exact multiples and rounds remainders up.
+fn as_milli_ceil_success() {
+    let exact = is_ok!(UTCTimestamp::builder().use_ms(1_234_567).build());
+    assert_eq!(exact.as_milli_ceil(), 1_234_567);
+    assert_eq!(exact.as_milli_ceil(), exact.as_milli());
+
+    let one_ns_over = is_ok!(UTCTimestamp::builder().use_ns(1_234_567_000_001).build());
+    assert_eq!(one_ns_over.as_milli(), 1_234_567);
+    assert_eq!(one_ns_over.as_milli_ceil(), 1_234_568);
+
+    let just_under = is_ok!(UTCTimestamp::builder().use_ns(1_234_567_999_999).build());
+    assert_eq!(just_under.as_milli_ceil(), 1_234_568);
+}
+
+#[test]
+/// Requirement validation: verifies the ceiling millisecond view caps at `u64::MAX` like the floor view.
+fn as_milli_ceil_cap_success() {
+    let at_max = is_ok!(UTCTimestamp::builder().use_ms(u64::MAX).build());
+    assert_eq!(at_max.as_milli_ceil(), u64::MAX);
+
+    // One nanosecond past u64::MAX ms rounds up to a value beyond u64 and is capped.
+    let past_max = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(u128::from(u64::MAX) * 1_000_000 + 1)
+            .build()
+    );
+    assert_eq!(past_max.as_milli(), u64::MAX);
+    assert_eq!(past_max.as_milli_ceil(), u64::MAX);
+
+    let largest = is_ok!(UTCTimestamp::builder().use_ns(u128::MAX).build());
+    assert_eq!(largest.as_milli_ceil(), u64::MAX);
+}
+
+#[test]
+/// Requirement validation: verifies `u64::MAX` milliseconds convert without overflow.#[test]
 /// Requirement validation: verifies `u64::MAX` milliseconds convert without overflow.
 fn ms_at_u64_max_success() {
     let ts = is_ok!(
@@ -61,6 +145,36 @@ fn ms_at_u64_max_success() {
     assert_eq!(ts.as_sec(), 18446744073709551u64);
 }
 
+#[test]
+/// Requirement validation: verifies checked millisecond input matches `use_ms` for in-range values.
+fn use_ms_checked_success() {
+    let checked = is_ok!(is_ok!(UTCTimestamp::builder().use_ms_checked(1_234_567u64)).build());
+    let unchecked = is_ok!(UTCTimestamp::builder().use_ms(1_234_567).build());
+    assert_eq!(checked, unchecked);
+
+    let at_u64_max = is_ok!(is_ok!(UTCTimestamp::builder().use_ms_checked(u64::MAX)).build());
+    assert_eq!(at_u64_max.as_milli(), u64::MAX);
+
+    let largest = u128::MAX / 1_000_000;
+    let ts = is_ok!(is_ok!(UTCTimestamp::builder().use_ms_checked(largest)).build());
+    assert_eq!(ts.as_nano(), largest * 1_000_000);
+}
+
+#[test]
+/// Requirement validation: verifies checked millisecond input rejects values that overflow nanoseconds.
+fn use_ms_checked_overflow_error() {
+    let too_large = u128::MAX / 1_000_000 + 1;
+    kernel_error_eq!(
+        UTCTimestamp::builder().use_ms_checked(too_large),
+        Kind::ExceedsMax,
+        Audience::System,
+        format!(
+            "The UTCTimestamp {} ms is too large to store in nanoseconds.",
+            too_large
+        )
+    );
+}
+
 #[test]
 /// Requirement validation: verifies very large nanosecond inputs cap the millisecond view.
 fn ns_triggers_millis_cap_success() {
@@ -88,6 +202,21 @@ fn ns_causes_seconds_wrap_to_zero_success() {
     assert_eq!(ts.as_sec(), 0u64); // cast wraps/truncates
 }
 
+#[test]
+/// Requirement validation: verifies the saturating seconds view caps instead of wrapping.
+fn ns_causes_seconds_saturate_success() {
+    let ts = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(18446744073709551616000000000u128) // (u64::MAX + 1) * 1_000_000_000
+            .build()
+    );
+    assert_eq!(ts.as_sec_saturating(), u64::MAX);
+    assert_eq!(ts.as_sec(), 0u64);
+
+    let in_range = is_ok!(UTCTimestamp::builder().use_ms(1_234_567).build());
+    assert_eq!(in_range.as_sec_saturating(), in_range.as_sec());
+}
+
 #[test]
 /// Requirement validation: verifies sub-millisecond inputs floor to zero for milli and seconds.
 fn small_ns_yields_zero_millis_and_seconds_success() {
@@ -118,3 +247,272 @@ fn missing_value_error() {
         "A value was not provided for the UTCTimestamp, please provide a valid UTCTimestamp value."
     );
 }
+
+#[test]
+/// Requirement validation: verifies a whole-second timestamp has no sub-second nanoseconds.
+fn subsec_nanos_whole_second_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_ms(1_700_000_000_000).build());
+    assert_eq!(ts.subsec_nanos(), 0);
+}
+
+#[test]
+/// Requirement validation: verifies the sub-second remainder is returned in nanoseconds.
+fn subsec_nanos_remainder_success() {
+    let ts = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(1_700_000_000_123_456_789)
+            .build()
+    );
+    assert_eq!(ts.subsec_nanos(), 123_456_789);
+    assert_eq!(ts.as_sec(), 1_700_000_000);
+}
+
+#[test]
+/// Requirement validation: verifies a sub-second duration is stored as nanoseconds and converts
+/// back unchanged.
+fn duration_sub_second_success() {
+    let duration = Duration::from_nanos(123_456_789);
+    let ts = is_ok!(UTCTimestamp::builder().use_duration(duration).build());
+    assert_eq!(ts.as_nano(), 123_456_789u128);
+    assert_eq!(ts.as_sec(), 0u64);
+    assert_eq!(ts.as_duration(), duration);
+}
+
+#[test]
+/// Requirement validation: verifies `Duration::MAX` round-trips exactly and a larger nanosecond
+/// value saturates at `Duration::MAX`.
+fn duration_saturation_boundary_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_duration(Duration::MAX).build());
+    assert_eq!(ts.as_nano(), Duration::MAX.as_nanos());
+    assert_eq!(ts.as_duration(), Duration::MAX);
+
+    let ts = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(Duration::MAX.as_nanos() + 1)
+            .build()
+    );
+    assert_eq!(ts.as_duration(), Duration::MAX);
+}
+
+#[test]
+/// Requirement validation: verifies a later `use_ms` call overrides an earlier `use_duration`.
+fn duration_overridden_by_ms_success() {
+    let ts = is_ok!(
+        UTCTimestamp::builder()
+            .use_duration(Duration::from_secs(5))
+            .use_ms(1_500u64)
+            .build()
+    );
+    assert_eq!(ts.as_nano(), 1_500_000_000u128);
+    assert_eq!(ts.as_duration(), Duration::from_millis(1_500));
+}
+
+/// 2000-01-01T00:00:00Z and 2100-01-01T00:00:00Z in milliseconds.
+const PLAUSIBLE_MS: (u64, u64) = (946_684_800_000, 4_102_444_800_000);
+
+#[test]
+/// Requirement validation: verifies a value within the plausible range builds, including at both
+/// inclusive bounds.
+fn plausible_range_within_success() {
+    for ms in [PLAUSIBLE_MS.0, 1_739_450_096_789, PLAUSIBLE_MS.1] {
+        let ts = is_ok!(
+            UTCTimestamp::builder()
+                .reject_before(PLAUSIBLE_MS.0)
+                .reject_after(PLAUSIBLE_MS.1)
+                .use_ms(ms)
+                .build()
+        );
+        assert_eq!(ts.as_milli(), ms);
+    }
+}
+
+#[test]
+/// Requirement validation: verifies seconds passed as milliseconds fall below the range.
+fn plausible_range_below_error() {
+    let result = UTCTimestamp::builder()
+        .reject_before(PLAUSIBLE_MS.0)
+        .reject_after(PLAUSIBLE_MS.1)
+        .use_ms(1_739_450_096)
+        .build();
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The UTCTimestamp 1739450096 ms is before the earliest accepted value of 946684800000 ms. Check that the value is in milliseconds."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies microseconds passed as milliseconds fall above the range.
+fn plausible_range_above_error() {
+    let result = UTCTimestamp::builder()
+        .reject_before(PLAUSIBLE_MS.0)
+        .reject_after(PLAUSIBLE_MS.1)
+        .use_ms(1_739_450_096_789_000)
+        .build();
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The UTCTimestamp 1739450096789000 ms is after the latest accepted value of 4102444800000 ms. Check that the value is in milliseconds."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies the Unix epoch renders as a compact timestamp.
+fn to_compact_epoch_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_ms(0u64).build());
+    assert_eq!(ts.to_compact(), "19700101T000000000");
+}
+
+#[test]
+/// Requirement validation: verifies known timestamps render as compact timestamps truncated to
+/// milliseconds, including a leap day.
+fn to_compact_known_timestamp_success() {
+    let ts = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(1_739_450_096_789_999_999u128)
+            .build()
+    );
+    assert_eq!(ts.to_compact(), "20250213T123456789");
+
+    let ts = is_ok!(UTCTimestamp::builder().use_ms(1_709_251_199_999u64).build());
+    assert_eq!(ts.to_compact(), "20240229T235959999");
+}
+
+#[test]
+/// Requirement validation: verifies compact timestamps are accepted as file names.
+fn to_compact_is_file_name_success() {
+    let compact = is_ok!(UTCTimestamp::builder().use_ms(1_739_450_096_789u64).build()).to_compact();
+    let file_name = is_ok!(FileName::builder().value(&compact).build());
+    assert_eq!(file_name.value(), compact);
+}
+
+#[test]
+/// Requirement validation: verifies converting to a start time truncates sub-millisecond
+/// nanoseconds, including past the `as_milli` cap.
+fn to_start_time_truncates_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_ns(1_500_999u128).build());
+    assert_eq!(ts.to_start_time(), StartTime::from(1));
+    assert_eq!(
+        ts.to_start_time().to_utc_timestamp().as_nano(),
+        1_000_000u128
+    );
+
+    let past_cap = (u128::from(u64::MAX) + 1) * 1_000_000;
+    let ts = is_ok!(UTCTimestamp::builder().use_ns(past_cap).build());
+    assert_eq!(ts.as_milli(), u64::MAX);
+    assert_eq!(
+        ts.to_start_time(),
+        StartTime::from(u128::from(u64::MAX) + 1)
+    );
+}
+
+#[test]
+/// Requirement validation: verifies comparison with a start time ignores sub-millisecond
+/// nanoseconds and orders whole milliseconds.
+fn cmp_start_time_success() {
+    let start_time = StartTime::from(1_700_000_000_000);
+    let on_boundary = is_ok!(UTCTimestamp::builder().use_ms(1_700_000_000_000u64).build());
+    let within_ms = is_ok!(
+        UTCTimestamp::builder()
+            .use_ns(1_700_000_000_000_999_999u128)
+            .build()
+    );
+    assert_eq!(on_boundary.cmp_start_time(&start_time), Ordering::Equal);
+    assert_eq!(within_ms.cmp_start_time(&start_time), Ordering::Equal);
+    assert_ne!(on_boundary, within_ms);
+
+    let later = is_ok!(UTCTimestamp::builder().use_ms(1_700_000_000_001u64).build());
+    assert_eq!(later.cmp_start_time(&start_time), Ordering::Greater);
+    let earlier = is_ok!(UTCTimestamp::builder().use_ms(1_699_999_999_999u64).build());
+    assert_eq!(earlier.cmp_start_time(&start_time), Ordering::Less);
+
+    let past_cap = StartTime::from(u128::from(u64::MAX) + 1);
+    let capped = is_ok!(UTCTimestamp::builder().use_ms(u64::MAX).build());
+    assert_eq!(capped.cmp_start_time(&past_cap), Ordering::Less);
+}
+
+#[test]
+/// Requirement validation: verifies half a millisecond and more rounds up.
+fn to_start_time_rounded_up_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_ns(1_500_000u128).build());
+    assert_eq!(is_ok!(ts.to_start_time_rounded()), StartTime::from(2));
+    assert_eq!(ts.to_start_time(), StartTime::from(1));
+
+    let ts = is_ok!(UTCTimestamp::builder().use_ns(1_999_999u128).build());
+    assert_eq!(is_ok!(ts.to_start_time_rounded()), StartTime::from(2));
+}
+
+#[test]
+/// Requirement validation: verifies less than half a millisecond rounds down.
+fn to_start_time_rounded_down_success() {
+    let ts = is_ok!(UTCTimestamp::builder().use_ns(1_499_999u128).build());
+    assert_eq!(is_ok!(ts.to_start_time_rounded()), StartTime::from(1));
+
+    let ts = is_ok!(UTCTimestamp::builder().use_ms(1_739_450_096_789u64).build());
+    assert_eq!(
+        is_ok!(ts.to_start_time_rounded()),
+        StartTime::from(1_739_450_096_789)
+    );
+}
+
+#[test]
+/// Requirement validation: verifies rounding that would overflow the nanosecond range is rejected.
+fn to_start_time_rounded_overflow_error() {
+    let ts = is_ok!(UTCTimestamp::builder().use_ns(u128::MAX).build());
+    kernel_error_eq!(
+        ts.to_start_time_rounded(),
+        Kind::InvalidInput,
+        Audience::System,
+        "The UTCTimestamp 340282366920938463463374607431768211455 ns cannot be rounded to milliseconds without overflowing."
+    );
+}
+
+fn iso_week_for_ms(ms: u64) -> (i32, u8) {
+    is_ok!(UTCTimestamp::builder().use_ms(ms).build()).iso_week()
+}
+
+#[test]
+/// Requirement validation: verifies dates known to be in week 1 of their ISO year.
+fn iso_week_first_week_success() {
+    assert_eq!(iso_week_for_ms(0), (1970, 1)); // 1970-01-01, Thursday
+    assert_eq!(iso_week_for_ms(1_704_067_200_000), (2024, 1)); // 2024-01-01, Monday
+    assert_eq!(iso_week_for_ms(1_704_067_200_000 + 86_399_999), (2024, 1));
+}
+
+#[test]
+/// Requirement validation: verifies dates in week 52 and week 53.
+fn iso_week_last_week_success() {
+    assert_eq!(iso_week_for_ms(1_672_444_800_000), (2022, 52)); // 2022-12-31, Saturday
+    assert_eq!(iso_week_for_ms(1_609_372_800_000), (2020, 53)); // 2020-12-31, Thursday
+}
+
+#[test]
+/// Requirement validation: verifies year-boundary dates whose ISO year differs from the calendar year.
+fn iso_week_year_boundary_success() {
+    assert_eq!(iso_week_for_ms(1_609_459_200_000), (2020, 53)); // 2021-01-01, Friday
+    assert_eq!(iso_week_for_ms(1_577_664_000_000), (2020, 1)); // 2019-12-30, Monday
+    assert_eq!(iso_week_for_ms(1_230_595_200_000), (2009, 1)); // 2008-12-30, Tuesday
+}
+
+#[test]
+/// Requirement validation: verifies the earliest and latest timestamps are selected regardless of slice order.
+fn min_max_of_populated_success() {
+    let early = is_ok!(UTCTimestamp::builder().use_ms(1_000).build());
+    let middle = is_ok!(UTCTimestamp::builder().use_ns(1_500_000_001).build());
+    let late = is_ok!(UTCTimestamp::builder().use_ms(2_000).build());
+    let timestamps = [middle, late, early, middle];
+
+    assert_eq!(UTCTimestamp::min_of(&timestamps), Some(early));
+    assert_eq!(UTCTimestamp::max_of(&timestamps), Some(late));
+    assert_eq!(UTCTimestamp::min_of(&[middle]), Some(middle));
+    assert_eq!(UTCTimestamp::max_of(&[middle]), Some(middle));
+}
+
+#[test]
+/// Requirement validation: verifies an empty slice has no earliest or latest timestamp.
+fn min_max_of_empty_success() {
+    assert_eq!(UTCTimestamp::min_of(&[]), None);
+    assert_eq!(UTCTimestamp::max_of(&[]), None);
+}
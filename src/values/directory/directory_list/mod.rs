@@ -1,4 +1,5 @@
 use crate::error::{Error, Kind};
+use crate::values::copy_value::CopyValue;
 use std::collections::HashMap;
 
 /// The [`DirectoryList`]  is a list of paths to directories or files which is used to model an expected directory structure, with files, on a file system which will store the evidence, specification, and other files.  This is not specific to any particular file system, but is a general model of a directory structure.
@@ -134,5 +135,16 @@ impl DirectoryList {
     }
 }
 
+impl CopyValue for DirectoryList {
+    /// Copies the [`DirectoryList`] by re-adding each path, so the copy is re-validated.
+    fn copy(&self) -> Result<DirectoryList, Error> {
+        self.paths
+            .iter()
+            .try_fold(DirectoryList::default(), |list, (path_name, path)| {
+                list.try_add(path_name, path)
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests;
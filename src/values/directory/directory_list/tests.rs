@@ -1,14 +1,16 @@
 //! Tests for `DirectoryList`, covering construction, insertion, lookup, and merge behavior.
 //!
 //! Bounded unit under test: `DirectoryList`.
-//! Public interfaces verified: `default`, `try_from_vec`, `try_add`, `try_get`, and `try_merge`.
+//! Public interfaces verified: `default`, `try_from_vec`, `try_add`, `try_get`, `try_merge`,
+//! `copy`, and `copy_with`.
 //! Logical paths covered: empty inputs, successful insertions, duplicate key handling, missing
-//! lookups, and list merge behavior.
+//! lookups, list merge behavior, and copies with an overridden path.
 //! Requirement validation points: standards-aligned value-object coverage for directory path
 //! collection behavior.
 
 use super::DirectoryList;
 use crate::error::{Audience, Kind};
+use crate::values::copy_value::CopyValue;
 use std::collections::HashMap;
 use test_framework_oss::{is_error, is_ok};
 
@@ -130,6 +132,31 @@ fn try_merge_duplicate_path_name_error() {
         "The path name 'path-key' already exists in the directory List.  Please provide a unique path name."
     );
 }
+#[test]
+/// Requirement validation: verifies `copy_with` overrides a path on the copy only.
+fn directory_list_copy_with_override_success() {
+    let directory_list = is_ok!(DirectoryList::try_from_vec(vec![
+        ("evidence".to_string(), "/path/to/evidence".to_string()),
+        ("reports".to_string(), "/path/to/reports".to_string()),
+    ]));
+
+    let copied = is_ok!(directory_list.copy_with(|list| {
+        list.paths[0].1 = "/other/path/to/evidence".to_string();
+    }));
+
+    assert_eq!(
+        copied.try_get("evidence"),
+        Some("/other/path/to/evidence".to_string())
+    );
+    assert_eq!(
+        copied.try_get("reports"),
+        Some("/path/to/reports".to_string())
+    );
+    assert_eq!(
+        directory_list.try_get("evidence"),
+        Some("/path/to/evidence".to_string())
+    );
+}
 
 /*** Sad Path Tests ***/
 #[test]
@@ -207,3 +234,24 @@ fn directory_list_get_not_found_error() {
     let result = directory_list.try_get("path-key-2");
     assert_eq!(result, None);
 }
+#[test]
+/// Requirement validation: verifies `copy` rejects a list holding duplicate path names.
+fn directory_list_copy_duplicate_path_name_error() {
+    let directory_list = DirectoryList {
+        paths: vec![
+            ("path-key".to_string(), "/path/to/directory".to_string()),
+            ("path-key".to_string(), "/path/to/directory-2".to_string()),
+        ],
+    };
+
+    let result = directory_list.copy_with(|list| list.paths.clear());
+
+    let error = is_error!(result);
+
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.audience, Audience::System);
+    assert_eq!(
+        error.message,
+        "The path name 'path-key' already exists in the directory List.  Please provide a unique path name."
+    );
+}
@@ -4,3 +4,5 @@
 pub mod directory_list;
 /// Directory name value helpers.
 pub mod name;
+/// Relative directory path value helpers.
+pub mod path;
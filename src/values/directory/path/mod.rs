@@ -0,0 +1,128 @@
+//! Relative directory value and builder.
+
+use crate::error::{Error, Kind};
+use crate::values::file_system::file_name::FileName;
+use std::fmt;
+
+/// A relative directory on the virtual file system, made of validated [`FileName`] segments.
+///
+/// A [`Directory`] can never be absolute and can never climb out of its root with `..`, so it is
+/// safe to join onto any base location. An empty [`Directory`] is the root itself.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Directory {
+    /// Directory segments, from outermost to innermost.
+    segments: Vec<FileName>,
+}
+
+impl Directory {
+    /// Create a new instance of a [`DirectoryBuilder`] to create a [`Directory`] instance.
+    pub fn builder() -> DirectoryBuilder {
+        DirectoryBuilder::default()
+    }
+
+    /// Retrieve the directory segments, from outermost to innermost.
+    pub fn segments(&self) -> &[FileName] {
+        &self.segments
+    }
+
+    /// Append a segment to the end of the directory.
+    pub fn push(&mut self, segment: FileName) {
+        self.segments.push(segment);
+    }
+
+    /// Create a new directory with the segments of `other` nested under this directory.
+    pub fn join(&self, other: &Directory) -> Directory {
+        Directory {
+            segments: self
+                .segments
+                .iter()
+                .chain(other.segments.iter())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Render the directory with `/` separators and no leading or trailing separator.
+    pub fn to_path_string(&self) -> String {
+        self.segments
+            .iter()
+            .map(FileName::value)
+            .collect::<Vec<&str>>()
+            .join("/")
+    }
+}
+
+impl fmt::Display for Directory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_path_string())
+    }
+}
+
+/// Use to build a valid instance of a [`Directory`] from `/` separated text.
+#[derive(Clone, Default)]
+pub struct DirectoryBuilder {
+    /// Raw directory path input.
+    path: Option<String>,
+}
+
+impl DirectoryBuilder {
+    /// Provide the relative directory path, such as `evidence/2024`.
+    ///
+    /// A single trailing `/` is accepted and ignored.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    /// Verify and build the [`Directory`] instance from the provided builder inputs.
+    pub fn try_build(self) -> Result<Directory, Error> {
+        let path = self.path.unwrap_or_default().trim().to_string();
+
+        if path.is_empty() {
+            return Err(invalid_input(
+                "The directory path is required, but was empty.".to_string(),
+            ));
+        }
+
+        if path.starts_with('/') {
+            return Err(invalid_input(format!(
+                "The directory path '{}' is absolute. Only relative directory paths are allowed.",
+                path
+            )));
+        }
+
+        let segments = path
+            .strip_suffix('/')
+            .unwrap_or(&path)
+            .split('/')
+            .map(|segment| build_segment(&path, segment))
+            .collect::<Result<Vec<FileName>, Error>>()?;
+
+        Ok(Directory { segments })
+    }
+}
+
+/// Validates a single `segment` of `path` as a [`FileName`].
+fn build_segment(path: &str, segment: &str) -> Result<FileName, Error> {
+    if segment == ".." {
+        return Err(invalid_input(format!(
+            "The directory path '{}' cannot contain '..' traversal.",
+            path
+        )));
+    }
+
+    FileName::builder().value(segment).build().map_err(|error| {
+        invalid_input(format!(
+            "The directory path '{}' has an invalid segment '{}'. {}",
+            path, segment, error.message
+        ))
+    })
+}
+
+/// All [`Directory`] validation errors are system-facing invalid input.
+fn invalid_input(message: String) -> Error {
+    Error::for_system(Kind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,130 @@
+//! Verifies the relative directory value object.
+//!
+//! Bounded unit under test:
+//! - `Directory`
+//!
+//! Public interfaces verified:
+//! - `Directory::builder().try_build()`
+//! - `Directory::push`
+//! - `Directory::join`
+//! - `Directory::to_path_string`
+//!
+//! Logical paths covered:
+//! - a multi-segment path is split into validated segments
+//! - segments are pushed and directories are joined in order
+//! - empty, absolute, `..` traversal, and invalid segment paths are rejected
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::Directory;
+use crate::error::Audience;
+use crate::error::Kind;
+use crate::values::file_system::file_name::FileName;
+use test_framework_oss::is_ok;
+use test_framework_oss::kernel_error_eq;
+
+fn directory(path: &str) -> Directory {
+    is_ok!(Directory::builder().path(path).try_build())
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a multi-segment path is split into its segments.
+#[test]
+fn multi_segment_success() {
+    let directory = directory("evidence/2024/run-1/");
+
+    let segments: Vec<&str> = directory.segments().iter().map(FileName::value).collect();
+    assert_eq!(segments, ["evidence", "2024", "run-1"]);
+    assert_eq!(directory.to_path_string(), "evidence/2024/run-1");
+    assert_eq!(directory.to_string(), "evidence/2024/run-1");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that pushing a segment appends it to the end of the directory.
+#[test]
+fn push_success() {
+    let mut directory = directory("evidence");
+
+    directory.push(is_ok!(FileName::builder().value("2024").build()));
+
+    assert_eq!(directory.to_path_string(), "evidence/2024");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that joining nests the other directory under this one without changing either.
+#[test]
+fn join_success() {
+    let base = directory("evidence/2024");
+    let nested = directory("run-1/logs");
+
+    let joined = base.join(&nested);
+
+    assert_eq!(joined.to_path_string(), "evidence/2024/run-1/logs");
+    assert_eq!(base.to_path_string(), "evidence/2024");
+    assert_eq!(nested.to_path_string(), "run-1/logs");
+    assert_eq!(Directory::default().join(&nested), nested);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a `..` segment is rejected.
+#[test]
+fn parent_traversal_error() {
+    let result = Directory::builder().path("evidence/../secrets").try_build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The directory path 'evidence/../secrets' cannot contain '..' traversal."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an absolute path is rejected.
+#[test]
+fn absolute_path_error() {
+    let result = Directory::builder().path("/etc/evidence").try_build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The directory path '/etc/evidence' is absolute. Only relative directory paths are allowed."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an empty path is rejected.
+#[test]
+fn empty_path_error() {
+    let result = Directory::builder().path("  ").try_build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The directory path is required, but was empty."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an empty segment between separators is rejected.
+#[test]
+fn empty_segment_error() {
+    let result = Directory::builder().path("evidence//2024").try_build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The directory path 'evidence//2024' has an invalid segment ''. The file name cannot be empty."
+    );
+}
@@ -1,6 +1,7 @@
 //! File-system file-name value and builder.
 
-use crate::error::{Error, Kind};
+use crate::error::message::MessageKey;
+use crate::error::{Audience, Error, Kind};
 use crate::values::Value;
 
 // A value representing the file name for a file on the virtual file system.
@@ -12,6 +13,54 @@ pub struct FileName {
 }
 
 impl FileName {
+    /// Human-readable description of the characters allowed in a [`FileName`].
+    pub const ALLOWED_CHARS_DESCRIPTION: &str =
+        "ASCII letters (a-z, A-Z), digits (0-9), '.', '_', and '-'";
+
+    /// Returns `true` when `c` is allowed anywhere in a [`FileName`].
+    ///
+    /// This is the same check the [`FileNameBuilder`] applies, so callers can pre-validate input.
+    pub fn is_valid_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-'
+    }
+
+    /// Returns the validation rules the default [`FileNameBuilder`] applies, as data.
+    ///
+    /// Use [`FileNameBuilder::constraints`] for the rules of a customized builder.
+    pub fn constraints() -> FileNameConstraints {
+        FileNameBuilder::default().constraints()
+    }
+
+    /// Coerces arbitrary input, such as a user-supplied title, into a valid [`FileName`].
+    ///
+    /// This never fails. Surrounding whitespace and leading invalid characters are dropped, each
+    /// remaining disallowed character becomes `_`, and runs of `_` collapse to one. When nothing
+    /// usable is left, or the result is `.` or `..`, the name is `unnamed`.
+    ///
+    /// ```rust
+    /// use kernel_oss::values::file_system::file_name::FileName;
+    ///
+    /// assert_eq!(FileName::sanitize("Q3 report/final?").value(), "Q3_report_final_");
+    /// ```
+    pub fn sanitize(input: &str) -> FileName {
+        let mut value = String::with_capacity(input.len());
+        for c in input
+            .trim()
+            .trim_start_matches(|c| !FileName::is_valid_char(c))
+            .chars()
+        {
+            let c = if FileName::is_valid_char(c) { c } else { '_' };
+            if !(c == '_' && value.ends_with('_')) {
+                value.push(c);
+            }
+        }
+
+        if value.is_empty() || FORBIDDEN_NAMES.contains(&value.as_str()) {
+            value = SANITIZED_FALLBACK.to_string();
+        }
+        FileName { value }
+    }
+
     /// Create a new instance of a [`FileNameBuilder`] to create a [`FileName`] instance.
     pub fn builder() -> FileNameBuilder {
         FileNameBuilder::default()
@@ -21,6 +70,14 @@ impl FileName {
     pub fn value(&self) -> &str {
         &self.value
     }
+
+    /// Returns `true` when both names are equal ignoring ASCII case, such as `Readme.txt` and
+    /// `readme.txt`.
+    ///
+    /// Use this to detect names that would collide on a case-insensitive backing store.
+    pub fn eq_ignore_case(&self, other: &FileName) -> bool {
+        self.value.eq_ignore_ascii_case(&other.value)
+    }
 }
 
 impl Value for FileName {
@@ -31,11 +88,37 @@ impl Value for FileName {
     }
 }
 
+/// The validation rules of a [`FileNameBuilder`], for clients that build their own input forms.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileNameConstraints {
+    /// The maximum length in bytes, or `None` when the length is not limited.
+    pub max_len: Option<usize>,
+    /// Every character allowed anywhere in the name, matching [`FileName::is_valid_char`].
+    pub allowed_chars: &'static str,
+    /// Names rejected outright even though every character is allowed.
+    pub forbidden_exact: &'static [&'static str],
+    /// Whether leading and trailing whitespace is removed before validation.
+    pub trims_whitespace: bool,
+    /// Whether the validated name is lowercased.
+    pub lowercases: bool,
+}
+
+/// Every character [`FileName::is_valid_char`] accepts.
+const ALLOWED_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._-";
+
+/// Names that are rejected even though they only contain allowed characters.
+const FORBIDDEN_NAMES: [&str; 2] = [".", ".."];
+
+/// The name [`FileName::sanitize`] produces when no usable characters remain.
+const SANITIZED_FALLBACK: &str = "unnamed";
+
 /// Use to build a valid instance of a [`FileName`].
 #[derive(Clone, Default)]
 pub struct FileNameBuilder {
     /// Raw file-name input.
     value: Option<String>,
+    /// Whether the validated name is lowercased.
+    lowercase: bool,
 }
 
 impl FileNameBuilder {
@@ -45,10 +128,34 @@ impl FileNameBuilder {
         self
     }
 
+    /// Lowercase the validated name, for backing stores that compare names case-insensitively.
+    ///
+    /// Names are case-preserving unless this is set.
+    pub fn to_lowercase(mut self) -> Self {
+        self.lowercase = true;
+        self
+    }
+
+    /// Returns the validation rules this builder applies, including its [`FileNameBuilder::to_lowercase`] setting.
+    pub fn constraints(&self) -> FileNameConstraints {
+        FileNameConstraints {
+            max_len: None,
+            allowed_chars: ALLOWED_CHARS,
+            forbidden_exact: &FORBIDDEN_NAMES,
+            trims_whitespace: true,
+            lowercases: self.lowercase,
+        }
+    }
+
     /// Verify and build the [FileName] instance from the provided builder inputs.
     pub fn build(self) -> Result<FileName, Error> {
         let valid_name = validate_name(self.value)?;
-        Ok(FileName { value: valid_name })
+        let value = if self.lowercase {
+            valid_name.to_ascii_lowercase()
+        } else {
+            valid_name
+        };
+        Ok(FileName { value })
     }
 }
 
@@ -57,38 +164,27 @@ fn validate_name(value: Option<String>) -> Result<String, Error> {
     let name = value.unwrap_or_default().trim().to_string();
 
     if name.is_empty() {
-        return Err(invalid_input("The file name cannot be empty."));
+        return Err(invalid_input(MessageKey::FileNameEmpty));
     }
 
-    if name == "." || name == ".." {
-        return Err(invalid_input("The file name cannot be '.' or '..'."));
+    if FORBIDDEN_NAMES.contains(&name.as_str()) {
+        return Err(invalid_input(MessageKey::FileNameDotSegment));
     }
 
-    if !name.chars().next().unwrap().is_ascii_alphanumeric()
-        && !name.starts_with('-')
-        && !name.starts_with('.')
-        && !name.starts_with('_')
-    {
-        return Err(invalid_input(
-            "The file name must start with a letter, number, '-', '.', or '_'.",
-        ));
+    if !name.chars().next().is_some_and(FileName::is_valid_char) {
+        return Err(invalid_input(MessageKey::FileNameInvalidStart));
     }
 
-    if !name
-        .chars()
-        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-')
-    {
-        return Err(invalid_input(
-            "The file name can only contain alphanumeric characters, '.', '_', or '-'.",
-        ));
+    if !name.chars().all(FileName::is_valid_char) {
+        return Err(invalid_input(MessageKey::FileNameInvalidCharacter));
     }
 
     Ok(name)
 }
 
 /// Simple way to prevent duplicative [Error] code since all of are the same [Kind] and for the same [Audience].
-fn invalid_input(message: &str) -> Error {
-    Error::for_system(Kind::InvalidInput, message.to_string())
+fn invalid_input(key: MessageKey) -> Error {
+    key.error(Audience::System, Kind::InvalidInput, &[])
 }
 
 #[cfg(test)]
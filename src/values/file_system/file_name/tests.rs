@@ -6,16 +6,28 @@
 //! Public interfaces verified:
 //! - `FileName::builder().build()`
 //! - `FileName::value`
+//! - `FileName::builder().to_lowercase()`
+//! - `FileName::eq_ignore_case`
+//! - `FileName::sanitize`
+//! - `FileName::constraints` and `FileNameBuilder::constraints`
+//! - `FileName::is_valid_char`
+//! - `FileName::ALLOWED_CHARS_DESCRIPTION`
 //!
 //! Logical paths covered:
 //! - valid file names are accepted
 //! - leading and trailing whitespace is normalized
+//! - names are case-preserving by default and lowercased when requested
+//! - names differing only in case compare equal ignoring case
+//! - sanitizing replaces spaces and slashes, drops invalid leading characters, and falls back
+//!   to `unnamed` for unusable input
+//! - the reported constraints match the default and a lowercasing builder
 //! - empty, dot, dot-dot, invalid-start, and invalid-character names are rejected
+//! - the character predicate agrees with the builder for representative characters
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
-use super::FileName;
+use super::{FileName, FileNameConstraints};
 use crate::error::Audience;
 use crate::error::Kind;
 use test_framework_oss::kernel_error_eq;
@@ -132,3 +144,140 @@ fn invalid_characters_error() {
         "The file name can only contain alphanumeric characters, '.', '_', or '-'."
     );
 }
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that `FileName::is_valid_char` agrees with the builder's acceptance
+/// for representative characters.
+#[test]
+fn is_valid_char_matches_builder_success() {
+    let characters = [
+        'a', 'Z', '0', '9', '.', '_', '-', ' ', '/', '\\', '+', ':', '*', '?', '"', '<', '>', '|',
+        '~', '%', '\t', 'é', 'ß', '日',
+    ];
+
+    for character in characters {
+        let accepted = FileName::builder()
+            .value(&format!("a{character}b"))
+            .build()
+            .is_ok();
+        assert_eq!(
+            FileName::is_valid_char(character),
+            accepted,
+            "predicate and builder disagree for {character:?}"
+        );
+    }
+
+    assert!(!FileName::ALLOWED_CHARS_DESCRIPTION.is_empty());
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that names keep their case by default and are lowercased when requested.
+#[test]
+fn to_lowercase_success() {
+    let preserved = is_ok!(FileName::builder().value("Readme.TXT").build());
+    assert_eq!(preserved.value(), "Readme.TXT");
+
+    let lowered = is_ok!(
+        FileName::builder()
+            .value(" Readme.TXT ")
+            .to_lowercase()
+            .build()
+    );
+    assert_eq!(lowered.value(), "readme.txt");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that names differing only in case are equal ignoring case and different names are not.
+#[test]
+fn eq_ignore_case_success() {
+    let mixed = is_ok!(FileName::builder().value("Readme.txt").build());
+    let lower = is_ok!(FileName::builder().value("readme.txt").build());
+    let other = is_ok!(FileName::builder().value("readme.md").build());
+
+    assert_ne!(mixed, lower);
+    assert!(mixed.eq_ignore_case(&lower));
+    assert!(lower.eq_ignore_case(&mixed));
+    assert!(!mixed.eq_ignore_case(&other));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that spaces and slashes become single underscores and the result is a valid name.
+#[test]
+fn sanitize_spaces_and_slashes_success() {
+    let sanitized = FileName::sanitize("  Quarterly   report / 2024\\final  ");
+
+    assert_eq!(sanitized.value(), "Quarterly_report_2024_final");
+    assert_eq!(
+        is_ok!(FileName::builder().value(sanitized.value()).build()),
+        sanitized
+    );
+    assert_eq!(FileName::sanitize("a__b").value(), "a_b");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that invalid leading characters are dropped rather than replaced.
+#[test]
+fn sanitize_leading_invalid_char_success() {
+    assert_eq!(FileName::sanitize("+notes.txt").value(), "notes.txt");
+    assert_eq!(FileName::sanitize("~/+draft").value(), "draft");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that input with nothing usable falls back to `unnamed`.
+#[test]
+fn sanitize_all_invalid_success() {
+    assert_eq!(FileName::sanitize("+*?/").value(), "unnamed");
+    assert_eq!(FileName::sanitize("   ").value(), "unnamed");
+    assert_eq!(FileName::sanitize("..").value(), "unnamed");
+    assert_eq!(FileName::sanitize("日本").value(), "unnamed");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the default constraints describe the builder's validation.
+#[test]
+fn constraints_default_success() {
+    let constraints = FileName::constraints();
+
+    assert_eq!(
+        constraints,
+        FileNameConstraints {
+            max_len: None,
+            allowed_chars: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._-",
+            forbidden_exact: &[".", ".."],
+            trims_whitespace: true,
+            lowercases: false,
+        }
+    );
+    assert!(
+        constraints
+            .allowed_chars
+            .chars()
+            .all(FileName::is_valid_char)
+    );
+    for forbidden in constraints.forbidden_exact {
+        is_error!(FileName::builder().value(forbidden).build());
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a customized builder reports its lowercasing setting.
+#[test]
+fn constraints_customized_builder_success() {
+    let builder = FileName::builder().to_lowercase();
+
+    let constraints = builder.constraints();
+
+    assert!(constraints.lowercases);
+    assert_eq!(
+        constraints.allowed_chars,
+        FileName::constraints().allowed_chars
+    );
+}
@@ -0,0 +1,143 @@
+//! File-system file-path value and builder.
+
+use crate::error::{Error, Kind};
+use crate::values::Value;
+use crate::values::file_system::file_name::FileName;
+
+/// The default maximum length, in bytes, of a combined directory and file name.
+pub const DEFAULT_MAX_PATH_LEN: usize = 4096;
+
+/// A directory joined with a [`FileName`] whose combined length is bounded.
+///
+/// Some storage backends cap the full path length, so the combined value is
+/// validated against a configurable maximum when the [`FilePath`] is built.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FilePath {
+    /// Directory portion of the path, without a trailing separator unless it is the root `/`.
+    directory: String,
+    /// File name portion of the path.
+    file_name: FileName,
+    /// Combined directory and file name.
+    value: String,
+}
+
+impl FilePath {
+    /// Create a new instance of a [`FilePathBuilder`] to create a [`FilePath`] instance.
+    pub fn builder() -> FilePathBuilder {
+        FilePathBuilder::default()
+    }
+
+    /// Retrieve the directory portion of the path.
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    /// Retrieve the file name portion of the path.
+    pub fn file_name(&self) -> &FileName {
+        &self.file_name
+    }
+
+    /// Retrieve the combined directory and file name.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Value for FilePath {
+    type ValueType = str;
+
+    fn value(&self) -> &Self::ValueType {
+        self.value.as_str()
+    }
+}
+
+/// Use to build a valid instance of a [`FilePath`].
+#[derive(Clone, Default)]
+pub struct FilePathBuilder {
+    /// Raw directory input.
+    directory: Option<String>,
+    /// Validated file name input.
+    file_name: Option<FileName>,
+    /// Maximum combined path length, defaulting to [`DEFAULT_MAX_PATH_LEN`].
+    max_path_len: Option<usize>,
+}
+
+impl FilePathBuilder {
+    /// Provide the directory that contains the file.
+    pub fn directory(mut self, directory: &str) -> Self {
+        self.directory = Some(directory.to_string());
+        self
+    }
+
+    /// Provide the name of the file within the directory.
+    pub fn file_name(mut self, file_name: &FileName) -> Self {
+        self.file_name = Some(file_name.clone());
+        self
+    }
+
+    /// Override the maximum combined path length, in bytes.
+    pub fn max_path_len(mut self, max_path_len: usize) -> Self {
+        self.max_path_len = Some(max_path_len);
+        self
+    }
+
+    /// Verify and build the [`FilePath`] instance from the provided builder inputs.
+    ///
+    /// # Errors
+    ///
+    /// - [`Kind::InvalidInput`] if the directory is empty or the file name is missing.
+    /// - [`Kind::ExceedsMax`] if the combined path is longer than the maximum path length.
+    pub fn try_build(self) -> Result<FilePath, Error> {
+        let directory = validate_directory(self.directory)?;
+        let file_name = self.file_name.ok_or_else(|| {
+            Error::for_system(
+                Kind::InvalidInput,
+                "The file name for the file path is required.".to_string(),
+            )
+        })?;
+        let max_path_len = self.max_path_len.unwrap_or(DEFAULT_MAX_PATH_LEN);
+
+        let value = if directory.ends_with('/') {
+            format!("{}{}", directory, file_name.value())
+        } else {
+            format!("{}/{}", directory, file_name.value())
+        };
+        if value.len() > max_path_len {
+            return Err(Error::for_system(
+                Kind::ExceedsMax,
+                format!(
+                    "The file path is {} bytes long, which exceeds the maximum path length of {} bytes.",
+                    value.len(),
+                    max_path_len
+                ),
+            ));
+        }
+
+        Ok(FilePath {
+            directory,
+            file_name,
+            value,
+        })
+    }
+}
+
+/// Trims the directory and strips any trailing separators before joining, keeping a single `/`
+/// when the directory is the root.
+fn validate_directory(value: Option<String>) -> Result<String, Error> {
+    let directory = value.unwrap_or_default().trim().to_string();
+
+    if directory.is_empty() {
+        return Err(Error::for_system(
+            Kind::InvalidInput,
+            "The directory for the file path cannot be empty.".to_string(),
+        ));
+    }
+
+    match directory.trim_end_matches('/') {
+        "" => Ok("/".to_string()),
+        trimmed => Ok(trimmed.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests;
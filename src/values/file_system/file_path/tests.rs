@@ -0,0 +1,142 @@
+//! Verifies the bounded file-path value object.
+//!
+//! Bounded unit under test:
+//! - `FilePath`
+//!
+//! Public interfaces verified:
+//! - `FilePath::builder().try_build()`
+//! - `FilePath::directory`
+//! - `FilePath::file_name`
+//! - `FilePath::value`
+//!
+//! Logical paths covered:
+//! - a directory and file name under the limit are joined
+//! - the root directory keeps its leading `/`
+//! - the default limit and a configured limit reject long paths
+//! - an empty directory and a missing file name are rejected
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::{DEFAULT_MAX_PATH_LEN, FilePath};
+use crate::error::Audience;
+use crate::error::Kind;
+use crate::values::file_system::file_name::FileName;
+use test_framework_oss::kernel_error_eq;
+use test_framework_oss::{is_error, is_ok};
+
+fn file_name(value: &str) -> FileName {
+    is_ok!(FileName::builder().value(value).build())
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a path under the limit joins the directory and file name.
+#[test]
+fn under_limit_success() {
+    let name = file_name("evidence.json");
+    let path = is_ok!(
+        FilePath::builder()
+            .directory(" /var/data/ ")
+            .file_name(&name)
+            .try_build()
+    );
+
+    assert_eq!(path.value(), "/var/data/evidence.json");
+    assert_eq!(path.directory(), "/var/data");
+    assert_eq!(path.file_name(), &name);
+
+    let exact = is_ok!(
+        FilePath::builder()
+            .directory("dir")
+            .file_name(&file_name("a.txt"))
+            .max_path_len(9)
+            .try_build()
+    );
+    assert_eq!(exact.value(), "dir/a.txt");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the root directory stays absolute instead of becoming an empty directory.
+#[test]
+fn root_directory_success() {
+    for root in ["/", " // "] {
+        let path = is_ok!(
+            FilePath::builder()
+                .directory(root)
+                .file_name(&file_name("evidence.json"))
+                .try_build()
+        );
+
+        assert_eq!(path.directory(), "/");
+        assert_eq!(path.value(), "/evidence.json");
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a path longer than the default limit is rejected.
+#[test]
+fn exceeds_default_limit_error() {
+    let directory = format!("/{}", "d".repeat(DEFAULT_MAX_PATH_LEN));
+    let path = FilePath::builder()
+        .directory(&directory)
+        .file_name(&file_name("evidence.json"))
+        .try_build();
+
+    is_error!(&path);
+    kernel_error_eq!(
+        &path,
+        Kind::ExceedsMax,
+        Audience::System,
+        "The file path is 4111 bytes long, which exceeds the maximum path length of 4096 bytes."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a configured limit is applied instead of the default.
+#[test]
+fn exceeds_configured_limit_error() {
+    let path = FilePath::builder()
+        .directory("dir")
+        .file_name(&file_name("ab.txt"))
+        .max_path_len(9)
+        .try_build();
+
+    is_error!(&path);
+    kernel_error_eq!(
+        &path,
+        Kind::ExceedsMax,
+        Audience::System,
+        "The file path is 10 bytes long, which exceeds the maximum path length of 9 bytes."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an empty directory and a missing file name are rejected.
+#[test]
+fn missing_parts_error() {
+    let no_directory = FilePath::builder()
+        .directory("   ")
+        .file_name(&file_name("a.txt"))
+        .try_build();
+    is_error!(&no_directory);
+    kernel_error_eq!(
+        &no_directory,
+        Kind::InvalidInput,
+        Audience::System,
+        "The directory for the file path cannot be empty."
+    );
+
+    let no_file_name = FilePath::builder().directory("/var/data").try_build();
+    is_error!(&no_file_name);
+    kernel_error_eq!(
+        &no_file_name,
+        Kind::InvalidInput,
+        Audience::System,
+        "The file name for the file path is required."
+    );
+}
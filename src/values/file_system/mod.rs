@@ -1,3 +1,4 @@
 //! File-system bounded values used by the kernel.
 
 pub mod file_name;
+pub mod file_path;
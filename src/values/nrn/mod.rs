@@ -3,6 +3,7 @@
 use crate::error::{Error, Kind};
 use crate::values::Value;
 use std::fmt::Display;
+use std::str::FromStr;
 use std::string::ToString;
 
 /// Encoders and decoders for NRNs in file-path form.
@@ -60,6 +61,33 @@ impl NRN {
         &self.nss
     }
 
+    /// Returns the resource, which is the last [`NSS`] part of the NRN.
+    ///
+    /// For `nrn:sourcecode:nape:project/nape-cli` the resource is `project/nape-cli`.
+    pub fn resource(&self) -> &str {
+        self.nss.last().map(NSS::value).unwrap_or_default()
+    }
+
+    /// Returns the resource type, which is the part of the [`NRN::resource`] before the first `/`.
+    ///
+    /// Returns [`None`] when the resource does not contain a `/`.
+    pub fn resource_type(&self) -> Option<&str> {
+        self.resource()
+            .split_once('/')
+            .map(|(resource_type, _)| resource_type)
+    }
+
+    /// Returns the resource id, which is the part of the [`NRN::resource`] after the first `/`.
+    ///
+    /// Returns the whole resource when it does not contain a `/`.
+    pub fn resource_id(&self) -> &str {
+        let resource = self.resource();
+        resource
+            .split_once('/')
+            .map(|(_, resource_id)| resource_id)
+            .unwrap_or(resource)
+    }
+
     /// Create a new NRN instance
     ///
     /// # Arguments
@@ -91,6 +119,37 @@ impl NRN {
             nss: _nss,
         })
     }
+    /// Returns `true` when this NRN matches the `pattern` NRN.
+    ///
+    /// The NIDs must be equal and both NRNs must have the same number of [`NSS`] parts. The parts
+    /// are compared in order, where a pattern part of `*` matches any single part. When the last
+    /// pattern part ends with `/*`, it matches any resource that starts with the text before the
+    /// `*`, so `project/*` matches `project/nape-cli` and `project/nape-cli/v1`.
+    ///
+    /// The NID cannot be a wildcard, since only the NIDs in [`ALLOWED_NRN_NID_LIST`] are valid.
+    pub fn matches(&self, pattern: &NRN) -> bool {
+        if self.nid != pattern.nid || self.nss.len() != pattern.nss.len() {
+            return false;
+        }
+
+        let last = self.nss.len().saturating_sub(1);
+        self.nss
+            .iter()
+            .zip(&pattern.nss)
+            .enumerate()
+            .all(|(index, (part, pattern_part))| {
+                let part = part.value();
+                match pattern_part.value() {
+                    "*" => true,
+                    resource_prefix if index == last && resource_prefix.ends_with("/*") => {
+                        let prefix = &resource_prefix[..resource_prefix.len() - 1];
+                        part.len() > prefix.len() && part.starts_with(prefix)
+                    }
+                    exact => part == exact,
+                }
+            })
+    }
+
     fn check_nrn_is_not_empty(nrn: &str) -> Result<(), Error> {
         if nrn.is_empty() {
             return Err(Error::for_user(
@@ -150,6 +209,26 @@ impl Value for NRN {
     }
 }
 
+impl FromStr for NRN {
+    type Err = Error;
+
+    /// Parses an [`NRN`] with the same validation as [`NRN::new`].
+    fn from_str(nrn: &str) -> Result<Self, Self::Err> {
+        NRN::new(nrn)
+    }
+}
+
+impl Display for NRN {
+    /// Writes the canonical `nrn:<nid>:<nss>` form from the parsed parts.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.scheme, self.nid)?;
+        for nss in &self.nss {
+            write!(f, ":{}", nss.value)?;
+        }
+        Ok(())
+    }
+}
+
 /// An [`NapeNID`] is an enumeration of the different types of resources that can be identified within an [`NRN`] namespace.  The [`ALLOWED_NRN_NID_LIST`] contains the list of [`NID`]s that are allowed.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum NapeNID {
@@ -1,15 +1,17 @@
 //! Tests for NRN parsing and NID/NSS helpers.
 //!
 //! Bounded unit under test: the NRN, NID, NSS, and NapeNID value objects.
-//! Public interfaces verified: `NRN::new`, `NID::new`, `NSS::new`, `NapeNID::new`, and display
-//! formatting for NIDs.
-//! Logical paths covered: valid NRNs, scheme/NID/NSS validation failures, display conversion,
-//! and NID registry checks.
+//! Public interfaces verified: `NRN::new`, `NRN::from_str`, `NRN::resource`, `NRN::resource_type`,
+//! `NRN::resource_id`, `NRN::matches`, `NID::new`, `NSS::new`, `NapeNID::new`, and display formatting for NRNs and
+//! NIDs.
+//! Logical paths covered: valid NRNs, scheme/NID/NSS validation failures, resource type and id
+//! splitting, display round trips, wildcard matching, and NID registry checks.
 //! Requirement validation points: standards-aligned parsing and validation behavior for the NRN
 //! domain helpers.
 
 use crate::error::{Audience, Kind};
 use crate::values::nrn::{NID, NRN, NSS, NapeNID};
+use test_framework_oss::kernel_error_eq;
 use test_framework_oss::{is_error, is_ok};
 
 /*** NRN Tests ***/
@@ -83,6 +85,76 @@ fn from_str_invalid_nss_error() {
         "Input 'invalid nss' contains whitespace character ' ' at position 7"
     );
 }
+#[test]
+/// Requirement validation: verifies the resource is split into a type and id on the first `/`.
+fn resource_type_and_id_success() {
+    let nrn = is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli/v1"));
+    assert_eq!(nrn.resource(), "project/nape-cli/v1");
+    assert_eq!(nrn.resource_type(), Some("project"));
+    assert_eq!(nrn.resource_id(), "nape-cli/v1");
+
+    let nrn = is_ok!(NRN::new("nrn:procedure:nape-cli"));
+    assert_eq!(nrn.resource(), "nape-cli");
+    assert_eq!(nrn.resource_type(), None);
+    assert_eq!(nrn.resource_id(), "nape-cli");
+}
+#[test]
+/// Requirement validation: verifies `Display` reconstructs the canonical string and parses back.
+fn display_round_trip_success() {
+    let text = "nrn:sourcecode:nape:project/nape-cli";
+    let nrn = is_ok!(text.parse::<NRN>());
+
+    assert_eq!(nrn.to_string(), text);
+    assert_eq!(is_ok!(nrn.to_string().parse::<NRN>()), nrn);
+}
+#[test]
+/// Requirement validation: verifies `NRN::from_str` rejects a value missing the `nrn` prefix.
+fn from_str_missing_prefix_error() {
+    let result = "sourcecode:nape:project/nape-cli".parse::<NRN>();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::User,
+        "You provided 'sourcecode:nape:project/nape-cli' as an NRN and the scheme 'sourcecode'  is not valid. Must be 'nrn'"
+    );
+}
+#[test]
+/// Requirement validation: verifies an NRN matches an identical pattern.
+fn matches_exact_success() {
+    let nrn = is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli"));
+    let pattern = is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli"));
+
+    assert!(nrn.matches(&pattern));
+}
+#[test]
+/// Requirement validation: verifies `*` matches any single NSS part.
+fn matches_single_segment_wildcard_success() {
+    let nrn = is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli"));
+
+    assert!(nrn.matches(&is_ok!(NRN::new("nrn:sourcecode:*:project/nape-cli"))));
+    assert!(nrn.matches(&is_ok!(NRN::new("nrn:sourcecode:nape:*"))));
+    assert!(!nrn.matches(&is_ok!(NRN::new("nrn:sourcecode:*:project/other"))));
+    assert!(!nrn.matches(&is_ok!(NRN::new("nrn:procedure:*:*"))));
+}
+#[test]
+/// Requirement validation: verifies a trailing `/*` in the resource matches by prefix.
+fn matches_resource_prefix_wildcard_success() {
+    let pattern = is_ok!(NRN::new("nrn:sourcecode:*:project/*"));
+
+    assert!(is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli")).matches(&pattern));
+    assert!(is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli/v1")).matches(&pattern));
+    assert!(!is_ok!(NRN::new("nrn:sourcecode:nape:project/")).matches(&pattern));
+    assert!(!is_ok!(NRN::new("nrn:sourcecode:nape:projects/nape-cli")).matches(&pattern));
+}
+#[test]
+/// Requirement validation: verifies NRNs with a different number of NSS parts do not match.
+fn matches_segment_count_differs_error() {
+    let nrn = is_ok!(NRN::new("nrn:sourcecode:nape:project/nape-cli"));
+
+    assert!(!nrn.matches(&is_ok!(NRN::new("nrn:sourcecode:*"))));
+    assert!(!nrn.matches(&is_ok!(NRN::new("nrn:sourcecode:*:*:*"))));
+}
 
 /** NAPE NID Tests ***/
 
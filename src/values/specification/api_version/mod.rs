@@ -3,8 +3,20 @@
 use crate::error::{Error, Kind};
 use std::str::FromStr;
 
+/// The specification versions this crate has models for, oldest first.
+///
+/// Each entry matches a versioned module such as
+/// [`v1_0_0`](crate::values::specification::v1_0_0).
+pub const KNOWN_VERSIONS: [APIVersion; 1] = [APIVersion {
+    major: 1,
+    minor: 0,
+    patch: 0,
+}];
+
 /// [`APIVersion`] represents the version of the NAPE API versioning primarily for the NAPE Specifications and follows the [Semantic Versioning 2.0.0 specification](https://github.com/semver/semver)
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+///
+/// Versions are ordered by major, then minor, then patch.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct APIVersion {
     /// Major version component.
     pub major: u8,
@@ -50,6 +62,46 @@ impl APIVersion {
         }
     }
 
+    /// Returns `true` when this version is the same as or newer than `other`.
+    ///
+    /// Use this for feature gating, such as enabling behavior only from version `2.0.0` onward.
+    pub fn at_least(&self, other: &APIVersion) -> bool {
+        self >= other
+    }
+
+    /// Parses a `major.minor.patch` version and checks that it is one of [`KNOWN_VERSIONS`].
+    ///
+    /// Use this for an incoming `apiVersion` field, so a dispatcher can reject a specification it
+    /// has no model for before reading the rest. Use [`FromStr`] to parse any well-formed version.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User)
+    /// is returned when the version is malformed, with the same messages as [`FromStr`], or when
+    /// it is well-formed but not a known version.
+    pub fn parse(version: &str) -> Result<APIVersion, Error> {
+        let parsed = APIVersion::from_str(version)?;
+        if !parsed.is_supported(&KNOWN_VERSIONS) {
+            let known = KNOWN_VERSIONS
+                .iter()
+                .map(APIVersion::as_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(invalid_input_error(format!(
+                "The api version '{}' is not a recognized specification version. Recognized versions are: {}.",
+                version, known
+            )));
+        }
+        Ok(parsed)
+    }
+
+    /// Returns `true` when this version is exactly one of `supported`.
+    ///
+    /// Unlike [`APIVersion::at_least`], a newer version than every supported one is not accepted.
+    pub fn is_supported(&self, supported: &[APIVersion]) -> bool {
+        supported.contains(self)
+    }
+
     /// Get the [`APIVersion`] as a string.
     pub fn as_string(&self) -> String {
         format!("{}.{}.{}", self.major, self.minor, self.patch)
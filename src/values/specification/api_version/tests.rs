@@ -10,18 +10,27 @@
 //! - `APIVersion::minor`
 //! - `APIVersion::patch`
 //! - `APIVersion::as_string`
+//! - `Ord` ordering
+//! - `APIVersion::at_least`
+//! - `APIVersion::parse`
+//! - `APIVersion::is_supported`
+//! - `KNOWN_VERSIONS`
 //!
 //! Logical paths covered:
 //! - valid semver-style values parse successfully
 //! - empty, malformed, and invalid numeric segments fail validation
+//! - versions order by major, then minor, then patch
+//! - "at least" checks accept equal and newer versions and reject older ones
+//! - checked parsing accepts known versions and rejects unknown or malformed ones
+//! - supported-set checks accept only exact members
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
-use super::APIVersion;
+use super::{APIVersion, KNOWN_VERSIONS};
 use crate::error::Audience;
 use crate::error::Kind;
-use test_framework_oss::{is_error, is_ok};
+use test_framework_oss::{is_error, is_ok, kernel_error_eq};
 
 /// Requirement validation: No requirement validation point is currently supplied.
 ///
@@ -129,3 +138,75 @@ fn new_api_version_invalid_patch_error() {
         "The patch version 'c' from the supplied api version of '1.2.c' is not a valid number. It must be a number between 0 and 255"
     );
 }
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that versions order by major, then minor, then patch.
+#[test]
+fn ordering_success() {
+    assert!(APIVersion::new(1, 0, 0) < APIVersion::new(1, 0, 1));
+    assert!(APIVersion::new(1, 0, 9) < APIVersion::new(1, 1, 0));
+    assert!(APIVersion::new(1, 9, 9) < APIVersion::new(2, 0, 0));
+    assert_eq!(
+        APIVersion::new(1, 2, 3).cmp(&APIVersion::new(1, 2, 3)),
+        std::cmp::Ordering::Equal
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that `at_least` accepts equal and newer versions and rejects older
+/// ones.
+#[test]
+fn at_least_success() {
+    assert!(APIVersion::new(2, 0, 0).at_least(&APIVersion::new(1, 0, 0)));
+    assert!(APIVersion::new(1, 1, 0).at_least(&APIVersion::new(1, 0, 9)));
+    assert!(APIVersion::new(1, 0, 0).at_least(&APIVersion::new(1, 0, 0)));
+    assert!(!APIVersion::new(0, 9, 0).at_least(&APIVersion::new(1, 0, 0)));
+    assert!(!APIVersion::new(1, 0, 0).at_least(&APIVersion::new(1, 0, 1)));
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that checked parsing accepts every known version.
+#[test]
+fn parse_known_version_success() {
+    assert_eq!(is_ok!(APIVersion::parse("1.0.0")), APIVersion::new(1, 0, 0));
+    for known in KNOWN_VERSIONS {
+        assert_eq!(is_ok!(APIVersion::parse(&known.as_string())), known);
+    }
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that checked parsing rejects a well-formed unknown version and keeps the format
+/// errors of `FromStr` for malformed input.
+#[test]
+fn parse_unknown_version_error() {
+    kernel_error_eq!(
+        APIVersion::parse("2.0.0"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The api version '2.0.0' is not a recognized specification version. Recognized versions are: 1.0.0."
+    );
+    kernel_error_eq!(
+        APIVersion::parse("1.0"),
+        Kind::InvalidInput,
+        Audience::User,
+        "Version string '1.0' is not in the format 'major.minor.patch'."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the supported-set check accepts exact members only.
+#[test]
+fn is_supported_success() {
+    let supported = [APIVersion::new(1, 0, 0), APIVersion::new(1, 2, 0)];
+
+    assert!(APIVersion::new(1, 0, 0).is_supported(&supported));
+    assert!(APIVersion::new(1, 2, 0).is_supported(&supported));
+    assert!(!APIVersion::new(1, 1, 0).is_supported(&supported));
+    assert!(!APIVersion::new(2, 0, 0).is_supported(&supported));
+    assert!(!APIVersion::new(1, 0, 0).is_supported(&[]));
+}
@@ -17,6 +17,8 @@ pub struct Action {
     pub test: FilePath,
     /// The path to the evidence file.
     pub evidence: FilePath,
+    /// The names of the actions that must run before this action.
+    pub depends_on: Vec<String>,
 }
 
 impl Action {
@@ -45,6 +47,11 @@ impl Action {
         &self.evidence
     }
 
+    /// Returns the names of the prerequisite actions, in the order they were declared.
+    pub fn depends_on(&self) -> &[String] {
+        &self.depends_on
+    }
+
     /// Creates a new builder for an assurance procedure action.
     pub fn builder() -> ActionBuilder {
         ActionBuilder::new()
@@ -58,6 +65,7 @@ pub struct ActionBuilder {
     description: Option<String>,
     test: Option<String>,
     evidence: Option<String>,
+    depends_on: Vec<String>,
 }
 
 impl ActionBuilder {
@@ -69,6 +77,7 @@ impl ActionBuilder {
             description: None,
             test: None,
             evidence: None,
+            depends_on: Vec::new(),
         }
     }
 
@@ -102,6 +111,14 @@ impl ActionBuilder {
         self
     }
 
+    /// Adds the name of an action that must run before this action.
+    ///
+    /// Call this once per prerequisite. Repeated names are kept only once.
+    pub fn depends_on(mut self, action_name: &str) -> ActionBuilder {
+        self.depends_on.push(action_name.to_string());
+        self
+    }
+
     /// Validates the builder and creates an [`Action`].
     pub fn try_build(self) -> Result<Action, Error> {
         let valid_name = self.build_name()?;
@@ -109,6 +126,7 @@ impl ActionBuilder {
         let valid_description = self.build_long_description()?;
         let valid_test = self.build_test_file_path()?;
         let valid_evidence = self.build_evidence_file_path()?;
+        let valid_depends_on = self.build_depends_on()?;
 
         Ok(Action {
             name: valid_name,
@@ -116,6 +134,7 @@ impl ActionBuilder {
             description: valid_description,
             test: valid_test,
             evidence: valid_evidence,
+            depends_on: valid_depends_on,
         })
     }
 
@@ -179,6 +198,23 @@ impl ActionBuilder {
             ))
         })
     }
+
+    fn build_depends_on(&self) -> Result<Vec<String>, Error> {
+        self.depends_on
+            .iter()
+            .try_fold(Vec::new(), |mut dependencies, dependency| {
+                let name = Name::try_from(dependency).map_err(|error| {
+                    custom_error(&format!(
+                        "There is an issue with the dependency '{}'. {}",
+                        dependency, error.message
+                    ))
+                })?;
+                if !dependencies.contains(&name.value) {
+                    dependencies.push(name.value);
+                }
+                Ok(dependencies)
+            })
+    }
 }
 
 impl Default for ActionBuilder {
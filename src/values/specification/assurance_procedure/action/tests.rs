@@ -1,9 +1,9 @@
 //! Tests for assurance-procedure `Action`, covering builder construction and validation.
 //!
 //! Bounded unit under test: `assurance_procedure::Action`.
-//! Public interfaces verified: `builder`, `ActionBuilder::default`, and `try_build`.
+//! Public interfaces verified: `builder`, `ActionBuilder::default`, `depends_on`, and `try_build`.
 //! Logical paths covered: valid construction, missing fields, and invalid field inputs across
-//! name, outcome, descriptions, and file-path/signature inputs.
+//! name, outcome, descriptions, file-path/signature inputs, and declared dependencies.
 //! Requirement validation points: standards-aligned action builder behavior for assurance
 //! procedures.
 
@@ -229,3 +229,41 @@ fn bad_evidence_file_path_error() {
         "The Action for an Assurance Procedure could not be created. There is an issue with the evidence file path ''. "
     );
 }
+
+#[test]
+/// Requirement validation: verifies declared dependencies are normalized and deduplicated.
+fn depends_on_success() {
+    let result = Action::builder()
+        .name("name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .depends_on("Setup")
+        .depends_on("setup")
+        .depends_on("lint")
+        .try_build();
+
+    let result = is_ok!(result);
+    assert_eq!(result.depends_on(), ["setup", "lint"]);
+}
+
+#[test]
+/// Requirement validation: verifies the builder rejects an invalid dependency name.
+fn depends_on_invalid_name_error() {
+    let result = Action::builder()
+        .name("name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .depends_on("not valid")
+        .try_build();
+
+    kernel_error_starts_with!(
+        result,
+        Kind::InvalidInput,
+        Audience::User,
+        "The Action for an Assurance Procedure could not be created. There is an issue with the dependency 'not valid'."
+    );
+}
@@ -1,6 +1,7 @@
 use crate::error::{Error, Kind};
 use crate::values::specification::assurance_procedure::action::Action;
 use crate::values::specification::assurance_procedure::activity::Activity;
+use std::collections::{HashMap, HashSet};
 
 /// A collection of activities specific to an assurance procedure definition.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
@@ -30,7 +31,8 @@ impl Activities {
     ///
     /// # Errors
     ///
-    /// Returns an error if the activity could not be added.
+    /// Returns an error if the activity could not be added, including when an activity with the
+    /// same normalized name already exists.
     ///
     /// Adds a new activity by name and description.
     pub fn add(
@@ -49,6 +51,19 @@ impl Activities {
                 ),
             )
         })?;
+        if new_activities
+            .list
+            .iter()
+            .any(|existing_activity| existing_activity.name == activity.name)
+        {
+            return Err(Error::for_user(
+                Kind::InvalidInput,
+                format!(
+                    "We could not add the activity '{}' to the list of activities: an activity named '{}' already exists. Activity names must be unique.",
+                    activity_name, activity.name.value
+                ),
+            ));
+        }
         new_activities.list.push(activity);
         Ok(new_activities)
     }
@@ -138,6 +153,42 @@ impl Activities {
         }
     }
 
+    /// Returns the activities in the order they were added.
+    pub fn in_order(&self) -> impl Iterator<Item = &Activity> {
+        self.list.iter()
+    }
+
+    /// Returns the zero-based position of the activity with the given name, if it exists.
+    pub fn position_of(&self, name: &str) -> Option<usize> {
+        self.list
+            .iter()
+            .position(|activity| activity.name.value == name)
+    }
+
+    /// Verifies that no two activities share a name.
+    ///
+    /// [`Activities::add`] already rejects duplicates, but the `list` field is public, so a
+    /// collection assembled directly should be checked before it is used in a procedure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for [`Audience::User`](crate::error::Audience::User) of [`Kind::InvalidInput`] naming the first duplicated activity.
+    pub fn validate_unique_names(&self) -> Result<(), Error> {
+        let mut seen = HashSet::with_capacity(self.list.len());
+        for activity in &self.list {
+            if !seen.insert(activity.name.value.as_str()) {
+                return Err(Error::for_user(
+                    Kind::InvalidInput,
+                    format!(
+                        "The activity name '{}' is used more than once. Activity names must be unique within a procedure.",
+                        activity.name.value
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// A count of all [`Action`]s across all activities.
     /// Returns the total number of actions across all activities.
     pub fn action_count(&self) -> usize {
@@ -152,6 +203,105 @@ impl Activities {
     pub fn count(&self) -> usize {
         self.list.len()
     }
+
+    /// Verifies the action dependencies across all activities form a directed acyclic graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for [`Audience::User`](crate::error::Audience::User) of [`Kind::InvalidInput`] if an action depends on an action that does not exist, or if the dependencies form a cycle. A cycle error names every action in the cycle, such as `a -> b -> a`.
+    pub fn validate_acyclic(&self) -> Result<(), Error> {
+        self.topological_plan().map(|_| ())
+    }
+
+    /// Returns the action names across all activities ordered so every action follows its prerequisites.
+    ///
+    /// Actions are visited in declaration order, so independent actions keep their relative order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Activities::validate_acyclic`].
+    pub fn topological_plan(&self) -> Result<Vec<String>, Error> {
+        let actions: Vec<&Action> = self
+            .list
+            .iter()
+            .flat_map(|activity| activity.actions.iter())
+            .collect();
+        let dependencies: HashMap<&str, &[String]> = actions
+            .iter()
+            .map(|action| (action.name.value.as_str(), action.depends_on.as_slice()))
+            .collect();
+
+        let mut states: HashMap<&str, VisitState> = HashMap::new();
+        let mut path: Vec<&str> = Vec::new();
+        let mut order: Vec<String> = Vec::new();
+        for action in actions {
+            visit(
+                action.name.value.as_str(),
+                &dependencies,
+                &mut states,
+                &mut path,
+                &mut order,
+            )?;
+        }
+        Ok(order)
+    }
+}
+
+/// Depth-first search state for an action while ordering dependencies.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum VisitState {
+    InProgress,
+    Done,
+}
+
+fn visit<'a>(
+    name: &'a str,
+    dependencies: &HashMap<&'a str, &'a [String]>,
+    states: &mut HashMap<&'a str, VisitState>,
+    path: &mut Vec<&'a str>,
+    order: &mut Vec<String>,
+) -> Result<(), Error> {
+    match states.get(name) {
+        Some(VisitState::Done) => return Ok(()),
+        Some(VisitState::InProgress) => {
+            let start = path.iter().position(|step| *step == name).unwrap_or(0);
+            let cycle: Vec<&str> = path[start..]
+                .iter()
+                .copied()
+                .chain(std::iter::once(name))
+                .collect();
+            return Err(Error::for_user(
+                Kind::InvalidInput,
+                format!(
+                    "The action dependencies contain a cycle: {}. Please remove one of these dependencies.",
+                    cycle.join(" -> ")
+                ),
+            ));
+        }
+        None => {}
+    }
+
+    states.insert(name, VisitState::InProgress);
+    path.push(name);
+    for dependency in dependencies.get(name).copied().unwrap_or_default() {
+        let dependency_name = dependencies
+            .get_key_value(dependency.as_str())
+            .map(|(key, _)| *key)
+            .ok_or_else(|| {
+                Error::for_user(
+                    Kind::InvalidInput,
+                    format!(
+                        "The action '{}' depends on the action '{}', which does not exist.",
+                        name, dependency
+                    ),
+                )
+            })?;
+        visit(dependency_name, dependencies, states, path, order)?;
+    }
+    path.pop();
+    states.insert(name, VisitState::Done);
+    order.push(name.to_string());
+    Ok(())
 }
 
 #[cfg(test)]
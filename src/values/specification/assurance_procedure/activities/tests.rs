@@ -1,9 +1,10 @@
 //! Tests for assurance-procedure `Activities`, covering merge and action-routing behavior.
 //!
 //! Bounded unit under test: `assurance_procedure::Activities`.
-//! Public interfaces verified: `default`, `add`, `merge`, and `add_activity`.
+//! Public interfaces verified: `default`, `add`, `merge`, `add_activity`, `in_order`,
+//! `position_of`, `validate_unique_names`, `validate_acyclic`, and `topological_plan`.
 //! Logical paths covered: empty state, merging, duplicate suppression, action routing, and
-//! invalid activity names.
+//! invalid or duplicate activity names, insertion-order iteration and position lookup, and action dependency graphs with and without cycles.
 //! Requirement validation points: standards-aligned activity collection behavior for assurance
 //! procedures.
 
@@ -25,6 +26,26 @@ fn action(name: &str, short: &str, long: &str) -> Action {
     )
 }
 
+fn dependent_action(name: &str, depends_on: &[&str]) -> Action {
+    let builder = depends_on.iter().fold(
+        Action::builder()
+            .name(name)
+            .short_description("Short Desc")
+            .long_description("Long Desc")
+            .test_file_path("test_file")
+            .evidence_file_path("evidence"),
+        |builder, dependency| builder.depends_on(dependency),
+    );
+    is_ok!(builder.try_build())
+}
+
+fn activities_with(actions: &[Action]) -> Activities {
+    let activities = is_ok!(Activities::default().add("activity-1", "Short Desc", "Long Desc"));
+    actions.iter().fold(activities, |activities, action| {
+        is_ok!(activities.add_activity("activity-1", action))
+    })
+}
+
 #[test]
 /// Requirement validation: verifies the default activities collection starts empty.
 fn default_activities_success() {
@@ -124,6 +145,74 @@ fn add_activity_error() {
     );
 }
 
+#[test]
+/// Requirement validation: verifies an activity with the same normalized name is rejected.
+fn add_duplicate_activity_error() {
+    let activities = is_ok!(Activities::default().add("activity-1", "Short Desc", "Long Desc"));
+    let result = activities.add("Activity-1", "Other Short", "Other Long");
+
+    let err = is_error!(result);
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "We could not add the activity 'Activity-1' to the list of activities: an activity named 'activity-1' already exists. Activity names must be unique."
+    );
+    assert_eq!(activities.count(), 1);
+}
+
+#[test]
+/// Requirement validation: verifies activities are iterated in insertion order.
+fn in_order_preserves_insertion_order_success() {
+    let activities = is_ok!(
+        Activities::default()
+            .add("zeta", "Short Desc", "Long Desc")
+            .and_then(|activities| activities.add("alpha", "Short Desc", "Long Desc"))
+            .and_then(|activities| activities.add("mid", "Short Desc", "Long Desc"))
+    );
+
+    let names: Vec<&str> = activities
+        .in_order()
+        .map(|activity| activity.name.value.as_str())
+        .collect();
+    assert_eq!(names, ["zeta", "alpha", "mid"]);
+}
+
+#[test]
+/// Requirement validation: verifies an activity's position can be looked up by name.
+fn position_of_success() {
+    let activities = is_ok!(
+        Activities::default()
+            .add("first", "Short Desc", "Long Desc")
+            .and_then(|activities| activities.add("second", "Short Desc", "Long Desc"))
+    );
+
+    assert_eq!(activities.position_of("first"), Some(0));
+    assert_eq!(activities.position_of("second"), Some(1));
+    assert_eq!(activities.position_of("missing"), None);
+}
+
+#[test]
+/// Requirement validation: verifies a directly assembled collection with a repeated name is rejected.
+fn validate_unique_names_error() {
+    let activity = is_ok!(Activity::new("activity-1", "Short Desc", "Long Desc"));
+    let unique = Activities {
+        list: vec![activity.clone()],
+    };
+    is_ok!(unique.validate_unique_names());
+
+    let duplicated = Activities {
+        list: vec![activity.clone(), activity],
+    };
+    let err = is_error!(duplicated.validate_unique_names());
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "The activity name 'activity-1' is used more than once. Activity names must be unique within a procedure."
+    );
+}
+
 #[test]
 /// Requirement validation: verifies actions cannot be added when the activity is missing.
 fn add_action_without_existing_activity_error() {
@@ -139,3 +228,80 @@ fn add_action_without_existing_activity_error() {
         "Activity 'activity-1' does not exist. The activity must exist before you can add an action to it.  Please add a activity with the name you provided, a short description, and a long description."
     );
 }
+
+#[test]
+/// Requirement validation: verifies dependencies forming a directed acyclic graph are accepted.
+fn validate_acyclic_success() {
+    let activities = activities_with(&[
+        dependent_action("test", &["build", "lint"]),
+        dependent_action("build", &["checkout"]),
+        dependent_action("lint", &["checkout"]),
+        dependent_action("checkout", &[]),
+    ]);
+
+    is_ok!(activities.validate_acyclic());
+}
+
+#[test]
+/// Requirement validation: verifies a two-action cycle is rejected and named.
+fn validate_acyclic_two_node_cycle_error() {
+    let activities = activities_with(&[
+        dependent_action("build", &["test"]),
+        dependent_action("test", &["build"]),
+    ]);
+
+    let err = is_error!(activities.validate_acyclic());
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "The action dependencies contain a cycle: build -> test -> build. Please remove one of these dependencies."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a dependency on an unknown action is rejected.
+fn validate_acyclic_unknown_dependency_error() {
+    let activities = activities_with(&[dependent_action("build", &["checkout"])]);
+
+    let err = is_error!(activities.validate_acyclic());
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "The action 'build' depends on the action 'checkout', which does not exist."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a linear dependency chain is planned prerequisites first.
+fn topological_plan_linear_chain_success() {
+    let activities = activities_with(&[
+        dependent_action("deploy", &["test"]),
+        dependent_action("test", &["build"]),
+        dependent_action("build", &["checkout"]),
+        dependent_action("checkout", &[]),
+    ]);
+
+    let plan = is_ok!(activities.topological_plan());
+    assert_eq!(plan, ["checkout", "build", "test", "deploy"]);
+}
+
+#[test]
+/// Requirement validation: verifies a cyclic dependency graph cannot be planned.
+fn topological_plan_cycle_error() {
+    let activities = activities_with(&[
+        dependent_action("checkout", &[]),
+        dependent_action("build", &["checkout", "deploy"]),
+        dependent_action("test", &["build"]),
+        dependent_action("deploy", &["test"]),
+    ]);
+
+    let err = is_error!(activities.topological_plan());
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "The action dependencies contain a cycle: build -> deploy -> test -> build. Please remove one of these dependencies."
+    );
+}
@@ -58,11 +58,15 @@ impl Activity {
     ///
     /// A new instance of the activity or a [`Error`] for [`Audience::User`](crate::error::Audience::User) of [`Kind::InvalidInput`] for any invalid arguments.
     ///
+    /// Activity names are used as map keys and in URLs, so after [`Name`] lowercases the name it
+    /// must contain only `a-z`, `0-9`, and `-`.
+    ///
     /// Creates a new activity from the provided strings.
     pub fn new(name: &str, short_desc: &str, long_desc: &str) -> Result<Activity, Error> {
         let valid_name = Name::try_from(name).map_err(|error| {
             custom_error(format!("The name has an issue: {}", error.message).as_str())
         })?;
+        check_slug_safe(&valid_name)?;
         let valid_short = ShortDescription::try_from(short_desc).map_err(|error| {
             custom_error(format!("The short description has an issue: {}", error.message).as_str())
         })?;
@@ -131,6 +135,24 @@ impl Activity {
     }
 }
 
+/// Rejects normalized names with characters outside `[a-z0-9-]`, such as non-ASCII letters.
+fn check_slug_safe(name: &Name) -> Result<(), Error> {
+    if name
+        .value
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Ok(());
+    }
+    Err(custom_error(
+        format!(
+            "The name '{}' is not slug-safe. A name must contain only the characters a-z, 0-9, and dashes.",
+            name.value
+        )
+        .as_str(),
+    ))
+}
+
 /// Creates a user-facing error for assurance procedure activity validation.
 pub fn custom_error(message: &str) -> Error {
     Error::for_user(
@@ -3,7 +3,8 @@
 //! Bounded unit under test: `assurance_procedure::Activity`.
 //! Public interfaces verified: `new`, `append_action`, and `add_expected_evidence`.
 //! Logical paths covered: successful construction, action addition, expected-evidence insertion,
-//! and validation failures for invalid name/description/evidence inputs.
+//! slug-safe name normalization, and validation failures for invalid name/description/evidence
+//! inputs and names that are not slug-safe.
 //! Requirement validation points: standards-aligned activity behavior for assurance procedures.
 
 use crate::error::{Audience, Kind};
@@ -61,8 +62,35 @@ fn activity_add_expected_evidence_success() {
     assert_eq!(activity.action_count(), 0);
 }
 
+#[test]
+/// Requirement validation: verifies a slug-safe name is accepted and uppercase letters are
+/// normalized to lowercase.
+fn new_slug_name_success() {
+    let activity = is_ok!(Activity::new("build-2024", "Short Desc", "Long Desc"));
+    assert_eq!(activity.name.value, "build-2024");
+
+    let activity = is_ok!(Activity::new("Build-Step", "Short Desc", "Long Desc"));
+    assert_eq!(activity.name.value, "build-step");
+}
+
 /** Sad path tests **/
 
+#[test]
+/// Requirement validation: verifies names with spaces or non-ASCII letters are rejected.
+fn new_activity_not_slug_safe_error() {
+    let err = is_error!(Activity::new("Build Step", "Short Desc", "Long Desc"));
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+
+    let err = is_error!(Activity::new("Café", "Short Desc", "Long Desc"));
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "There is an issue with the activity information: The name 'café' is not slug-safe. A name must contain only the characters a-z, 0-9, and dashes."
+    );
+}
+
 #[test]
 /// Requirement validation: verifies invalid activity names are rejected.
 fn new_activity_invalid_name_error() {
@@ -9,6 +9,12 @@ pub struct Artifacts {
 }
 
 impl Artifacts {
+    /// Creates an [`ArtifactsBuilder`], the validated entry point for assembling a non-empty
+    /// collection from [`Artifact`] values.
+    pub fn builder() -> ArtifactsBuilder {
+        ArtifactsBuilder::default()
+    }
+
     /// Adds an artifact by name, description, and metadata.
     pub fn add(
         self,
@@ -35,6 +41,11 @@ impl Artifacts {
         Ok(new_artifacts)
     }
 
+    /// Returns the artifacts in this collection.
+    pub fn list(&self) -> &[Artifact] {
+        &self.artifacts
+    }
+
     /// Merges an existing artifact into the collection.
     pub fn merge(self, artifact: &Artifact) -> Result<Artifacts, Error> {
         if self
@@ -55,12 +66,89 @@ impl Artifacts {
         Ok(new_artifacts)
     }
 
+    /// Combines this collection with `other`, such as when composing two procedures.
+    ///
+    /// Artifacts from `other` are appended in order. An artifact identical to one already present
+    /// is kept only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for [`Audience::User`](crate::error::Audience::User) of [`Kind::Conflict`]
+    /// if both collections define an artifact with the same name but a different description or
+    /// expected metadata.
+    pub fn merge_all(self, other: Artifacts) -> Result<Artifacts, Error> {
+        let mut merged = self;
+        for artifact in other.artifacts {
+            match merged
+                .artifacts
+                .iter()
+                .find(|existing_artifact| existing_artifact.name == artifact.name)
+            {
+                Some(existing_artifact) if *existing_artifact == artifact => {}
+                Some(_) => {
+                    return Err(Error::for_user(
+                        Kind::Conflict,
+                        format!(
+                            "The artifact '{}' is defined differently in the artifacts being merged. Please make both definitions identical or rename one of them.",
+                            artifact.name.value
+                        ),
+                    ));
+                }
+                None => merged.artifacts.push(artifact),
+            }
+        }
+        Ok(merged)
+    }
+
     /// Returns the number of artifacts.
     pub fn count(self) -> usize {
         self.artifacts.len()
     }
 }
 
+/// Builds a non-empty [`Artifacts`] collection from validated [`Artifact`] values.
+///
+/// Each [`Artifact`] is already validated by [`Artifact::new`]. The builder checks the collection
+/// as a whole when [`ArtifactsBuilder::try_build`] is called: artifacts keep the order they were
+/// added in, an artifact added again unchanged is kept once, and the collection must not be empty.
+///
+/// An [`Artifact`] has no repository link or URI, so there is no per-artifact link to validate.
+#[derive(Clone, Debug, Default)]
+pub struct ArtifactsBuilder {
+    /// The artifacts in the order they were added.
+    artifacts: Vec<Artifact>,
+}
+
+impl ArtifactsBuilder {
+    /// Adds `artifact` to the collection.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, artifact: Artifact) -> Self {
+        self.artifacts.push(artifact);
+        self
+    }
+
+    /// Builds the collection.
+    ///
+    /// # Errors
+    ///
+    /// * An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User)
+    ///   when no artifact was added.
+    /// * An [`Error`] of [`Kind::Conflict`] for [`Audience::User`](crate::error::Audience::User)
+    ///   when two artifacts share a name but differ in description or expected metadata, as
+    ///   reported by [`Artifacts::merge_all`].
+    pub fn try_build(self) -> Result<Artifacts, Error> {
+        if self.artifacts.is_empty() {
+            return Err(Error::for_user(
+                Kind::InvalidInput,
+                "The artifacts cannot be empty. Please add at least one artifact.",
+            ));
+        }
+        Artifacts::default().merge_all(Artifacts {
+            artifacts: self.artifacts,
+        })
+    }
+}
+
 fn validate_artifact(
     name: &str,
     description: &str,
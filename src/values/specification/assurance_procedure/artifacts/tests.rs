@@ -1,15 +1,19 @@
 //! Tests for assurance-procedure `Artifacts`, covering merge and duplicate detection behavior.
 //!
 //! Bounded unit under test: `assurance_procedure::Artifacts`.
-//! Public interfaces verified: `default`, `add`, and `merge`.
-//! Logical paths covered: successful insertion, merging, and duplicate-artifact rejection.
+//! Public interfaces verified: `default`, `add`, `merge`, `merge_all`, and `builder` with
+//! `ArtifactsBuilder::add` and `ArtifactsBuilder::try_build`.
+//! Logical paths covered: successful insertion, merging, duplicate-artifact rejection, and
+//! combining collections with identical duplicates deduped and conflicting definitions rejected,
+//! and building a collection in order with identical duplicates deduped, conflicting definitions
+//! rejected, and an empty collection rejected.
 //! Requirement validation points: standards-aligned artifact collection behavior for assurance
 //! procedures.
 
 use crate::error::{Audience, Kind};
 use crate::values::specification::assurance_procedure::artifact::Artifact;
 use crate::values::specification::assurance_procedure::artifacts::Artifacts;
-use test_framework_oss::{is_error, is_ok};
+use test_framework_oss::{is_error, is_ok, kernel_error_eq};
 
 #[test]
 /// Requirement validation: verifies artifacts can be added successfully.
@@ -83,3 +87,122 @@ fn merge_artifact_already_exists_error() {
     assert_eq!(error.audience, Audience::User);
     assert!(error.message.starts_with("The artifact 'artifact-1' cannot be added because an artifact with the name 'artifact-1' already exists."));
 }
+
+fn artifacts_with(entries: &[(&str, &str)]) -> Artifacts {
+    entries
+        .iter()
+        .fold(Artifacts::default(), |artifacts, (name, description)| {
+            is_ok!(artifacts.add(
+                name,
+                description,
+                &[("key-1".to_string(), "value-1".to_string())]
+            ))
+        })
+}
+
+fn names(artifacts: &Artifacts) -> Vec<&str> {
+    artifacts
+        .list()
+        .iter()
+        .map(|artifact| artifact.name.value.as_str())
+        .collect()
+}
+
+#[test]
+/// Requirement validation: verifies collections with distinct artifacts merge in order.
+fn merge_all_success() {
+    let first = artifacts_with(&[("artifact-1", "first"), ("artifact-2", "second")]);
+    let second = artifacts_with(&[("artifact-3", "third")]);
+
+    let merged = is_ok!(first.merge_all(second));
+
+    assert_eq!(names(&merged), ["artifact-1", "artifact-2", "artifact-3"]);
+}
+
+#[test]
+/// Requirement validation: verifies identical artifacts in both collections are kept once.
+fn merge_all_identical_duplicate_success() {
+    let first = artifacts_with(&[("artifact-1", "first"), ("artifact-2", "second")]);
+    let second = artifacts_with(&[("artifact-2", "second"), ("artifact-3", "third")]);
+
+    let merged = is_ok!(first.merge_all(second));
+
+    assert_eq!(names(&merged), ["artifact-1", "artifact-2", "artifact-3"]);
+}
+
+#[test]
+/// Requirement validation: verifies same-named artifacts with different definitions conflict.
+fn merge_all_conflict_error() {
+    let first = artifacts_with(&[("artifact-1", "first")]);
+    let second = artifacts_with(&[("artifact-1", "a different description")]);
+
+    let error = is_error!(first.merge_all(second));
+
+    assert_eq!(error.kind, Kind::Conflict);
+    assert_eq!(error.audience, Audience::User);
+    assert_eq!(
+        error.message,
+        "The artifact 'artifact-1' is defined differently in the artifacts being merged. Please make both definitions identical or rename one of them."
+    );
+}
+
+fn artifact(name: &str, description: &str) -> Artifact {
+    is_ok!(Artifact::new(
+        name,
+        description,
+        &[("key-1".to_string(), "value-1".to_string())]
+    ))
+}
+
+#[test]
+/// Requirement validation: verifies the builder keeps added artifacts in order.
+fn builder_add_success() {
+    let artifacts = is_ok!(
+        Artifacts::builder()
+            .add(artifact("artifact-2", "second"))
+            .add(artifact("artifact-1", "first"))
+            .try_build()
+    );
+
+    assert_eq!(names(&artifacts), ["artifact-2", "artifact-1"]);
+    assert_eq!(artifacts.list()[1], artifact("artifact-1", "first"));
+}
+
+#[test]
+/// Requirement validation: verifies an artifact added again unchanged is kept once.
+fn builder_dedupes_identical_success() {
+    let artifacts = is_ok!(
+        Artifacts::builder()
+            .add(artifact("artifact-1", "first"))
+            .add(artifact("artifact-2", "second"))
+            .add(artifact("artifact-1", "first"))
+            .try_build()
+    );
+
+    assert_eq!(names(&artifacts), ["artifact-1", "artifact-2"]);
+}
+
+#[test]
+/// Requirement validation: verifies same-named artifacts with different definitions are rejected.
+fn builder_conflicting_name_error() {
+    kernel_error_eq!(
+        Artifacts::builder()
+            .add(artifact("artifact-1", "first"))
+            .add(artifact("artifact-1", "a different description"))
+            .try_build(),
+        Kind::Conflict,
+        Audience::User,
+        "The artifact 'artifact-1' is defined differently in the artifacts being merged. Please make both definitions identical or rename one of them."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies building without any artifact is rejected.
+fn builder_empty_error() {
+    kernel_error_eq!(
+        Artifacts::builder().try_build(),
+        Kind::InvalidInput,
+        Audience::User,
+        "The artifacts cannot be empty. Please add at least one artifact."
+    );
+}
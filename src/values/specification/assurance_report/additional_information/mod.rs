@@ -1,8 +1,14 @@
 use crate::error::{Error, Kind};
 use crate::values::specification::description::Description;
+use crate::values::strings::exceeds_max_length;
 use std::collections::HashSet;
 
+/// The default maximum length, in bytes, of a single entry of [`AdditionalInformation`].
+pub const DEFAULT_MAX_ENTRY_LENGTH: usize = 1024;
+
 /// The [`AdditionalInformation`] allows you to provide information above and beyond the current assurance report data specification. The [`AdditionalInformation`] struct can be used to represent and manage a list of additional information.
+///
+/// Entries keep their insertion order. Removing an entry leaves the remaining entries in order, and adding an entry, including one that was previously removed, appends it to the end.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct AdditionalInformation {
     list: Vec<Description>,
@@ -15,6 +21,13 @@ impl AdditionalInformation {
         AdditionalInformationBuilder::new()
     }
 
+    /// Create a new instance of the [`AdditionalInformationBuilder`] with room for `capacity` entries.
+    ///
+    /// Use this for bulk report generation when the number of entries is known up front, so appending them does not reallocate.
+    pub fn builder_with_capacity(capacity: usize) -> AdditionalInformationBuilder {
+        AdditionalInformationBuilder::with_capacity(capacity)
+    }
+
     /// # Overview
     ///
     /// Get a reference to the list of additional information.
@@ -29,6 +42,77 @@ impl AdditionalInformation {
     pub fn count(&self) -> usize {
         self.list.len()
     }
+
+    /// # Overview
+    ///
+    /// Check whether the list of additional information contains the provided information.
+    ///
+    /// The information is compared after leading and trailing whitespace is removed, matching how it is stored.
+    pub fn contains(&self, info: &str) -> bool {
+        let info = info.trim();
+        self.list.iter().any(|entry| entry.value() == info)
+    }
+
+    /// # Overview
+    ///
+    /// Appends a statement of information to the end of the list of additional information, unless it is already present.
+    ///
+    /// # Errors
+    ///
+    /// * If the information is invalid, including control characters other than newline and tab or zero-width characters, an [`Error`] is returned of kind [`Kind::InvalidInput`] for the audience [`Audience::User`](crate::error::Audience::User), and the list is left unchanged.
+    /// * If the information is longer than [`DEFAULT_MAX_ENTRY_LENGTH`], an [`Error`] is returned of kind [`Kind::ExceedsMax`] for the audience [`Audience::User`](crate::error::Audience::User), and the list is left unchanged.
+    ///
+    pub fn try_add(&mut self, info: &str) -> Result<(), Error> {
+        let description = validate_entry(info, DEFAULT_MAX_ENTRY_LENGTH)?;
+        if !self.list.contains(&description) {
+            self.list.push(description);
+        }
+        Ok(())
+    }
+
+    /// # Overview
+    ///
+    /// Removes the provided information from the list of additional information.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the information was present and removed, otherwise `false`.
+    ///
+    pub fn remove(&mut self, info: &str) -> bool {
+        let info = info.trim();
+        match self.list.iter().position(|entry| entry.value() == info) {
+            Some(index) => {
+                self.list.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a AdditionalInformation {
+    type Item = &'a Description;
+    type IntoIter = std::slice::Iter<'a, Description>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+/// Collects entries into [`AdditionalInformation`] using the same trimming, deduplication, and
+/// validation as [`AdditionalInformationBuilder::try_build`] with [`DEFAULT_MAX_ENTRY_LENGTH`].
+///
+/// `FromIterator` cannot fail, so invalid entries are silently dropped. Use the builder when
+/// invalid input should be reported instead.
+impl FromIterator<String> for AdditionalInformation {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut information = AdditionalInformation::default();
+        for info in iter {
+            // Invalid entries are dropped by design; see the impl docs.
+            let _ = information.try_add(&info);
+        }
+        information
+    }
 }
 
 /// The [`AdditionalInformationBuilder`] struct is used to create a list of [`AdditionalInformation`] for an assurance report. This builder applies all the validation logic to the list of additional information.
@@ -36,12 +120,24 @@ impl AdditionalInformation {
 #[derive(Clone, Debug)]
 pub struct AdditionalInformationBuilder {
     list: Vec<String>,
+    max_entry_length: usize,
 }
 
 impl AdditionalInformationBuilder {
     /// Create a new instance of the [`AdditionalInformationBuilder`].
     pub fn new() -> Self {
-        Self { list: Vec::new() }
+        Self {
+            list: Vec::new(),
+            max_entry_length: DEFAULT_MAX_ENTRY_LENGTH,
+        }
+    }
+
+    /// Create a new instance of the [`AdditionalInformationBuilder`] that reserves room for `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            list: Vec::with_capacity(capacity),
+            max_entry_length: DEFAULT_MAX_ENTRY_LENGTH,
+        }
     }
 
     /// # Overview
@@ -69,6 +165,24 @@ impl AdditionalInformationBuilder {
         self
     }
 
+    /// # Overview
+    ///
+    /// Appends every item to the list of additional information, in iteration order.
+    ///
+    /// Items are deduplicated and trimmed when the list is built, exactly as with [`AdditionalInformationBuilder::append`].
+    pub fn add_all<I: IntoIterator<Item = impl Into<String>>>(mut self, items: I) -> Self {
+        self.list.extend(items.into_iter().map(Into::into));
+        self
+    }
+
+    /// # Overview
+    ///
+    /// Sets the maximum length, in bytes, of a single trimmed entry. Defaults to [`DEFAULT_MAX_ENTRY_LENGTH`].
+    pub fn max_entry_length(mut self, max_entry_length: usize) -> Self {
+        self.max_entry_length = max_entry_length;
+        self
+    }
+
     /// # Overview
     ///
     /// Attempt to build the list of additional information.
@@ -79,7 +193,8 @@ impl AdditionalInformationBuilder {
     ///
     /// # Errors
     ///
-    /// * If the list of additional information contains invalid information, an [`Error`] is returned of kind [`Kind::InvalidInput`] for the audience [`Audience::User`](crate::error::Audience::User).
+    /// * If the list of additional information contains invalid information, including control characters other than newline and tab or zero-width characters, an [`Error`] is returned of kind [`Kind::InvalidInput`] for the audience [`Audience::User`](crate::error::Audience::User).
+    /// * If an entry is longer than the maximum entry length, an [`Error`] is returned of kind [`Kind::ExceedsMax`] for the audience [`Audience::User`](crate::error::Audience::User).
     ///
     pub fn try_build(self) -> Result<AdditionalInformation, Error> {
         let valid_descriptions = self.validate_information()?;
@@ -89,25 +204,66 @@ impl AdditionalInformationBuilder {
     }
 
     fn validate_information(self) -> Result<Vec<Description>, Error> {
-        let mut unique_entries = HashSet::new();
-        self.list
-            .into_iter()
-            .filter_map(|info| {
-                if unique_entries.insert(info.clone()) {
-                    Some(Description::try_from(&info).map_err(|error| {
-                        Error::for_user(
-                            Kind::InvalidInput,
-                            format!(
-                                "We could not add the additional information '{}'. {}",
-                                info, error.message
-                            ),
-                        )
-                    }))
-                } else {
-                    None
-                }
-            })
-            .collect()
+        let max_entry_length = self.max_entry_length;
+        let mut unique_entries = HashSet::with_capacity(self.list.len());
+        let mut descriptions = Vec::with_capacity(self.list.len());
+        for info in self.list {
+            if unique_entries.insert(info.trim().to_string()) {
+                descriptions.push(validate_entry(&info, max_entry_length)?);
+            }
+        }
+        Ok(descriptions)
+    }
+}
+
+/// Zero-width code points that are invisible when rendered but still change the text.
+const ZERO_WIDTH_CHARS: [char; 5] = ['\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{FEFF}'];
+
+fn validate_entry(info: &str, max_entry_length: usize) -> Result<Description, Error> {
+    if let Some(invalid) = info
+        .chars()
+        .find(|c| (c.is_control() && *c != '\n' && *c != '\t') || ZERO_WIDTH_CHARS.contains(c))
+    {
+        return Err(Error::for_user(
+            Kind::InvalidInput,
+            format!(
+                "We could not add the additional information '{}'. An entry cannot contain control characters other than newline and tab, or zero-width characters, but it contains U+{:04X}.",
+                info.escape_debug(),
+                u32::from(invalid)
+            ),
+        ));
+    }
+    let description = Description::try_from(info).map_err(|error| {
+        Error::for_user(
+            Kind::InvalidInput,
+            format!(
+                "We could not add the additional information '{}'. {}",
+                info, error.message
+            ),
+        )
+    })?;
+    if exceeds_max_length(description.value(), max_entry_length) {
+        return Err(Error::for_user(
+            Kind::ExceedsMax,
+            format!(
+                "We could not add the additional information '{}'. An entry must be at most {} bytes long, but it is {} bytes.",
+                preview(description.value()),
+                max_entry_length,
+                description.value().len()
+            ),
+        ));
+    }
+    Ok(description)
+}
+
+/// The number of characters of an overlong entry echoed back in its error message.
+const PREVIEW_CHARS: usize = 32;
+
+/// Shortens `info` to its first [`PREVIEW_CHARS`] characters, marking the cut with `...`.
+fn preview(info: &str) -> String {
+    match info.char_indices().nth(PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}...", &info[..end]),
+        None => info.to_string(),
     }
 }
 
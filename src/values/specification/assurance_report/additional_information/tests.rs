@@ -1,16 +1,24 @@
-//! Tests for assurance-report `AdditionalInformation`, covering append and deduplication.
+//! Tests for assurance-report `AdditionalInformation`, covering append, deduplication, and removal.
 //!
 //! Bounded unit under test: `assurance_report::AdditionalInformation`.
-//! Public interfaces verified: `builder`, `AdditionalInformationBuilder::default`, and `try_build`.
-//! Logical paths covered: successful append, duplicate suppression, and invalid entry rejection.
+//! Public interfaces verified: `builder`, `builder_with_capacity`, `AdditionalInformationBuilder::default`,
+//! `AdditionalInformationBuilder::with_capacity`, `add_all`,
+//! `max_entry_length`, `try_build`, `contains`, `try_add`, `remove`, `IntoIterator` for a reference,
+//! and `FromIterator<String>`.
+//! Logical paths covered: successful append, pre-sized builders, bulk add with duplicates, mixed append and bulk add,
+//! duplicate suppression, invalid and overlong entry rejection including byte-counted multibyte text, multiline entries, rejection of
+//! zero-width, NUL, and other control characters, internal spaces, containment checks,
+//! order-preserving removal, missing-entry removal, re-adding after removal, iteration, and
+//! collecting with invalid entries dropped.
 //! Requirement validation points: standards-aligned additional-information behavior for reports.
 
 use crate::error::{Audience, Kind};
 use crate::values::specification::assurance_report::additional_information::{
-    AdditionalInformation, AdditionalInformationBuilder,
+    AdditionalInformation, AdditionalInformationBuilder, DEFAULT_MAX_ENTRY_LENGTH,
 };
 use test_framework_oss::is_ok;
 use test_framework_oss::kernel_error_contains;
+use test_framework_oss::kernel_error_eq;
 
 #[test]
 /// Requirement validation: verifies additional information can be appended successfully.
@@ -24,6 +32,25 @@ fn add_success() {
     assert_eq!(additional_info.list()[0].value, "This is a test");
 }
 
+#[test]
+/// Requirement validation: verifies a pre-sized builder reserves its capacity and builds like the default builder.
+fn builder_with_capacity_success() {
+    let builder = AdditionalInformation::builder_with_capacity(64);
+    assert!(builder.list.capacity() >= 64);
+
+    let entries: Vec<String> = (0..64).map(|index| format!("Entry {index}")).collect();
+    let additional_info = is_ok!(builder.add_all(entries.iter().cloned()).try_build());
+    assert_eq!(additional_info.count(), 64);
+    assert_eq!(additional_info.list()[63].value, "Entry 63");
+
+    let expected = is_ok!(
+        AdditionalInformation::builder()
+            .add_all(entries)
+            .try_build()
+    );
+    assert_eq!(additional_info, expected);
+}
+
 #[test]
 /// Requirement validation: verifies the default builder can append information successfully.
 fn default_builder_success() {
@@ -60,3 +87,269 @@ fn add_error() {
         "We could not add the additional information ' '. "
     );
 }
+
+#[test]
+/// Requirement validation: verifies `contains` matches stored entries, ignoring surrounding whitespace.
+fn contains_success() {
+    let additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .append("First note")
+            .try_build()
+    );
+
+    assert!(additional_info.contains("First note"));
+    assert!(additional_info.contains("  First note "));
+    assert!(!additional_info.contains("Second note"));
+}
+
+#[test]
+/// Requirement validation: verifies removal keeps the remaining entries in insertion order.
+fn remove_preserves_order_success() {
+    let mut additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .append("First note")
+            .append("Second note")
+            .append("Third note")
+            .try_build()
+    );
+
+    assert!(additional_info.remove("Second note"));
+
+    assert_eq!(additional_info.count(), 2);
+    assert_eq!(additional_info.list()[0].value, "First note");
+    assert_eq!(additional_info.list()[1].value, "Third note");
+    assert!(!additional_info.contains("Second note"));
+}
+
+#[test]
+/// Requirement validation: verifies removing a value that is not present returns `false`.
+fn remove_not_present_error() {
+    let mut additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .append("First note")
+            .try_build()
+    );
+
+    assert!(!additional_info.remove("Missing note"));
+    assert_eq!(additional_info.count(), 1);
+}
+
+#[test]
+/// Requirement validation: verifies re-adding a removed value appends it at the end.
+fn re_add_after_remove_success() {
+    let mut additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .append("First note")
+            .append("Second note")
+            .try_build()
+    );
+
+    assert!(additional_info.remove("First note"));
+    is_ok!(additional_info.try_add("First note"));
+    is_ok!(additional_info.try_add("Second note"));
+
+    assert_eq!(additional_info.count(), 2);
+    assert_eq!(additional_info.list()[0].value, "Second note");
+    assert_eq!(additional_info.list()[1].value, "First note");
+}
+
+#[test]
+/// Requirement validation: verifies `try_add` rejects invalid entries and leaves the list unchanged.
+fn try_add_error() {
+    let mut additional_info = AdditionalInformation::default();
+
+    let result = additional_info.try_add(" ");
+
+    kernel_error_contains!(
+        &result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not add the additional information ' '. "
+    );
+    assert_eq!(additional_info.count(), 0);
+}
+
+#[test]
+/// Requirement validation: verifies `add_all` deduplicates and trims a vec of entries.
+fn add_all_with_duplicates_success() {
+    let notes = vec!["First note", " Second note ", "First note", "Second note"];
+
+    let additional_info = is_ok!(AdditionalInformation::builder().add_all(notes).try_build());
+
+    assert_eq!(additional_info.count(), 2);
+    assert_eq!(additional_info.list()[0].value, "First note");
+    assert_eq!(additional_info.list()[1].value, "Second note");
+}
+
+#[test]
+/// Requirement validation: verifies `append` and `add_all` can be mixed while preserving order.
+fn append_and_add_all_mixed_success() {
+    let additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .append("First note")
+            .add_all(vec!["Second note".to_string(), "First note".to_string()])
+            .append("Third note")
+            .try_build()
+    );
+
+    assert_eq!(additional_info.count(), 3);
+    assert_eq!(additional_info.list()[0].value, "First note");
+    assert_eq!(additional_info.list()[1].value, "Second note");
+    assert_eq!(additional_info.list()[2].value, "Third note");
+}
+
+#[test]
+/// Requirement validation: verifies entries longer than the configured cap are rejected.
+fn overlong_entry_error() {
+    let result = AdditionalInformation::builder()
+        .max_entry_length(10)
+        .add_all(["Short", "This note is too long"])
+        .try_build();
+
+    kernel_error_contains!(
+        &result,
+        Kind::ExceedsMax,
+        Audience::User,
+        "We could not add the additional information 'This note is too long'. An entry must be at most 10 bytes long, but it is 21 bytes."
+    );
+
+    let at_default_cap = "a".repeat(DEFAULT_MAX_ENTRY_LENGTH);
+    let over_default_cap = "a".repeat(DEFAULT_MAX_ENTRY_LENGTH + 1);
+    is_ok!(
+        AdditionalInformation::builder()
+            .append(&at_default_cap)
+            .try_build()
+    );
+    let result = AdditionalInformation::default().try_add(&over_default_cap);
+    kernel_error_contains!(
+        &result,
+        Kind::ExceedsMax,
+        Audience::User,
+        "An entry must be at most 1024 bytes long, but it is 1025 bytes."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a multiline entry with tabs is accepted unchanged.
+fn multiline_entry_success() {
+    let info = "First line\n\tIndented second line";
+    let additional_info = is_ok!(AdditionalInformation::builder().append(info).try_build());
+
+    assert_eq!(additional_info.list()[0].value, info);
+}
+
+#[test]
+/// Requirement validation: verifies an entry with an embedded zero-width space is rejected.
+fn zero_width_character_error() {
+    let result = AdditionalInformation::builder()
+        .append("Reviewed\u{200B}by QA")
+        .try_build();
+
+    kernel_error_eq!(
+        &result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not add the additional information 'Reviewed\\u{200b}by QA'. An entry cannot contain control characters other than newline and tab, or zero-width characters, but it contains U+200B."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies control characters are rejected by `try_add` and the list is
+/// left unchanged.
+fn control_character_error() {
+    let mut additional_info = AdditionalInformation::default();
+
+    let result = additional_info.try_add("Bell\u{7}here");
+
+    kernel_error_contains!(
+        &result,
+        Kind::InvalidInput,
+        Audience::User,
+        "but it contains U+0007."
+    );
+    assert_eq!(additional_info.count(), 0);
+}
+
+#[test]
+/// Requirement validation: verifies an entry with an embedded NUL character is rejected.
+fn nul_character_error() {
+    let result = AdditionalInformation::builder()
+        .append("Signed\u{0}off")
+        .try_build();
+
+    kernel_error_eq!(
+        &result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not add the additional information 'Signed\\0off'. An entry cannot contain control characters other than newline and tab, or zero-width characters, but it contains U+0000."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies runs of internal spaces are kept and only the ends are trimmed.
+fn internal_spaces_entry_success() {
+    let additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .max_entry_length(24)
+            .append("  Reviewed  by   QA team  ")
+            .try_build()
+    );
+
+    assert_eq!(additional_info.list()[0].value, "Reviewed  by   QA team");
+}
+
+#[test]
+/// Requirement validation: verifies iterating a built collection yields entries in insertion order.
+fn iterate_success() {
+    let additional_info = is_ok!(
+        AdditionalInformation::builder()
+            .add_all(["First", "Second", "Third"])
+            .try_build()
+    );
+
+    let values: Vec<&str> = (&additional_info)
+        .into_iter()
+        .map(|entry| entry.value())
+        .collect();
+    assert_eq!(values, ["First", "Second", "Third"]);
+
+    let mut count = 0;
+    for _entry in &additional_info {
+        count += 1;
+    }
+    assert_eq!(count, 3);
+}
+
+#[test]
+/// Requirement validation: verifies collecting trims, deduplicates, and drops invalid entries.
+fn collect_success() {
+    let additional_info: AdditionalInformation =
+        [" First ", "First", "   ", "Bad\u{0}entry", "Second"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+    assert_eq!(additional_info.count(), 2);
+    assert_eq!(additional_info.list()[0].value, "First");
+    assert_eq!(additional_info.list()[1].value, "Second");
+}
+
+#[test]
+/// Requirement validation: verifies the length limit counts bytes, so multibyte text over the
+/// limit is rejected, and that the echoed entry is shortened.
+fn overlong_multibyte_entry_error() {
+    let result = AdditionalInformation::builder()
+        .max_entry_length(40)
+        .append(&"é".repeat(35))
+        .try_build();
+
+    kernel_error_eq!(
+        &result,
+        Kind::ExceedsMax,
+        Audience::User,
+        format!(
+            "We could not add the additional information '{}...'. An entry must be at most 40 bytes long, but it is 70 bytes.",
+            "é".repeat(32)
+        )
+    );
+}
@@ -0,0 +1,89 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::traits::AssuranceReport;
+
+/// A field whose value differs between a baseline report and a newer report.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ChangedEntry {
+    /// The name of the field.
+    pub name: String,
+    /// The value in the baseline report.
+    pub before: String,
+    /// The value in the newer report.
+    pub after: String,
+}
+
+/// The structured difference between two assurance reports of the same concrete type.
+///
+/// Entries are compared by name using [`AssuranceReport::entries`], and each list is ordered by
+/// entry name.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ReportDiff {
+    /// Entries present only in the newer report, as `(name, value)` pairs.
+    pub added: Vec<(String, String)>,
+    /// Entries present only in the baseline report, as `(name, value)` pairs.
+    pub removed: Vec<(String, String)>,
+    /// Entries present in both reports with different values.
+    pub changed: Vec<ChangedEntry>,
+}
+
+impl ReportDiff {
+    /// Returns `true` when the two reports had no differing entries.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares a baseline report against a newer report.
+///
+/// Only the fields returned by [`AssuranceReport::entries`] are compared. A report type that
+/// keeps the default `entries` always produces an empty diff.
+///
+/// # Errors
+///
+/// Returns an error for [`Audience::User`](crate::error::Audience::User) of [`Kind::InvalidInput`]
+/// if the reports have a different `api_version` or `kind`, or are different concrete types.
+pub fn diff(
+    baseline: &dyn AssuranceReport,
+    other: &dyn AssuranceReport,
+) -> Result<ReportDiff, Error> {
+    if baseline.api_version() != other.api_version() || baseline.kind() != other.kind() {
+        return Err(Error::for_user(
+            Kind::InvalidInput,
+            format!(
+                "We could not compare the reports: the baseline is a '{}' report at api version '{}' but the other is a '{}' report at api version '{}'. Only reports of the same kind and api version can be compared.",
+                baseline.kind(),
+                baseline.api_version().as_string(),
+                other.kind(),
+                other.api_version().as_string()
+            ),
+        ));
+    }
+    if baseline.as_any().type_id() != other.as_any().type_id() {
+        return Err(Error::for_user(
+            Kind::InvalidInput,
+            "We could not compare the reports: they are different report types. Only reports of the same type can be compared.",
+        ));
+    }
+
+    let before = baseline.entries();
+    let mut after = other.entries();
+    let mut result = ReportDiff::default();
+    for (name, before_value) in before {
+        match after.remove(&name) {
+            Some(after_value) if after_value != before_value => {
+                result.changed.push(ChangedEntry {
+                    name,
+                    before: before_value,
+                    after: after_value,
+                });
+            }
+            Some(_) => {}
+            None => result.removed.push((name, before_value)),
+        }
+    }
+    result.added.extend(after);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,134 @@
+//! Tests for assurance report diffing, covering comparisons between baseline and newer runs.
+//!
+//! Bounded unit under test: `assurance_report::diff`.
+//! Public interfaces verified: `diff`, `dyn AssuranceReport::diff`, and `ReportDiff::is_empty`.
+//! Logical paths covered: identical reports, changed, added, and removed entries, and reports
+//! with a different api version or concrete type.
+//! Requirement validation points: standards-aligned comparison of assurance report runs.
+
+use super::{ChangedEntry, diff};
+use crate::error::{Audience, Kind};
+use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::traits::AssuranceReport;
+use std::any::Any;
+use std::collections::BTreeMap;
+use test_framework_oss::{is_error, is_ok};
+
+#[derive(Debug)]
+struct FieldReport {
+    version: APIVersion,
+    fields: BTreeMap<String, String>,
+}
+
+impl FieldReport {
+    fn new(fields: &[(&str, &str)]) -> Self {
+        FieldReport {
+            version: APIVersion::new(1, 0, 0),
+            fields: fields
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl AssuranceReport for FieldReport {
+    fn api_version(&self) -> APIVersion {
+        self.version.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn entries(&self) -> BTreeMap<String, String> {
+        self.fields.clone()
+    }
+}
+
+#[derive(Debug)]
+struct OtherReport;
+
+impl AssuranceReport for OtherReport {
+    fn api_version(&self) -> APIVersion {
+        APIVersion::new(1, 0, 0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[test]
+/// Requirement validation: verifies identical reports produce an empty diff.
+fn diff_identical_reports_success() {
+    let baseline = FieldReport::new(&[("outcome", "pass"), ("info.owner", "team-a")]);
+    let other = FieldReport::new(&[("outcome", "pass"), ("info.owner", "team-a")]);
+
+    let result = is_ok!(diff(&baseline, &other));
+    assert!(result.is_empty());
+}
+
+#[test]
+/// Requirement validation: verifies a single changed field is reported with both values.
+fn diff_changed_field_success() {
+    let baseline = FieldReport::new(&[("outcome", "pass"), ("info.owner", "team-a")]);
+    let other = FieldReport::new(&[("outcome", "fail"), ("info.owner", "team-a")]);
+
+    let result = is_ok!(diff(&baseline, &other));
+    assert!(result.added.is_empty());
+    assert!(result.removed.is_empty());
+    assert_eq!(
+        result.changed,
+        vec![ChangedEntry {
+            name: "outcome".to_string(),
+            before: "pass".to_string(),
+            after: "fail".to_string(),
+        }]
+    );
+}
+
+#[test]
+/// Requirement validation: verifies entries only in one report are listed as added or removed.
+fn diff_added_and_removed_fields_success() {
+    let baseline: Box<dyn AssuranceReport> = Box::new(FieldReport::new(&[("info.old", "1")]));
+    let other: Box<dyn AssuranceReport> = Box::new(FieldReport::new(&[("info.new", "2")]));
+
+    let result = is_ok!(baseline.diff(other.as_ref()));
+    assert_eq!(
+        result.added,
+        vec![("info.new".to_string(), "2".to_string())]
+    );
+    assert_eq!(
+        result.removed,
+        vec![("info.old".to_string(), "1".to_string())]
+    );
+    assert!(result.changed.is_empty());
+}
+
+#[test]
+/// Requirement validation: verifies reports at different api versions cannot be compared.
+fn diff_different_api_version_error() {
+    let baseline = FieldReport::new(&[]);
+    let mut other = FieldReport::new(&[]);
+    other.version = APIVersion::new(2, 0, 0);
+
+    let err = is_error!(diff(&baseline, &other));
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(
+        err.message,
+        "We could not compare the reports: the baseline is a 'AssuranceReport' report at api version '1.0.0' but the other is a 'AssuranceReport' report at api version '2.0.0'. Only reports of the same kind and api version can be compared."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies reports of different concrete types cannot be compared.
+fn diff_different_report_type_error() {
+    let err = is_error!(diff(&FieldReport::new(&[]), &OtherReport));
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(
+        err.message,
+        "We could not compare the reports: they are different report types. Only reports of the same type can be compared."
+    );
+}
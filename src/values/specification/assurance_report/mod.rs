@@ -9,6 +9,8 @@ pub mod additional_information;
 pub mod activities;
 /// Assurance report activities.
 pub mod activity;
+/// Structured comparison of two assurance reports.
+pub mod diff;
 /// Signed file values used by assurance reports.
 pub mod signed_file;
 /// Assurance report summaries.
@@ -14,6 +14,7 @@ pub mod metadata;
 pub mod name;
 pub mod outcome;
 pub mod procedure;
+pub mod registry;
 pub mod repository_link;
 pub mod short_description;
 pub mod subject;
@@ -0,0 +1,98 @@
+//! Dispatch from a declared specification version and kind to the code that builds it.
+
+use crate::error::{self, Error};
+use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::kind::Kind;
+use crate::values::specification::traits::AssuranceProcedure;
+use std::collections::HashMap;
+
+/// Builds an assurance procedure from the raw text of a specification document.
+pub type ProcedureConstructor =
+    Box<dyn Fn(&str) -> Result<Box<dyn AssuranceProcedure>, Error> + Send + Sync>;
+
+/// A registry of [`ProcedureConstructor`]s keyed by the `(APIVersion, Kind)` a document declares.
+///
+/// Read the `apiVersion` and `kind` fields of an incoming document first, then call
+/// [`SpecRegistry::build`] to hand the raw text to the matching constructor. Each pair has at
+/// most one constructor, so plugins cannot silently replace one another.
+#[derive(Default)]
+pub struct SpecRegistry {
+    /// Constructors keyed by the version and kind they build.
+    constructors: HashMap<(APIVersion, Kind), ProcedureConstructor>,
+}
+
+impl SpecRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        SpecRegistry {
+            constructors: HashMap::new(),
+        }
+    }
+
+    /// Registers `constructor` for documents declaring `api_version` and `kind`.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::Conflict`](crate::error::Kind::Conflict) for
+    /// [`Audience::System`](crate::error::Audience::System) is returned when a constructor is
+    /// already registered for the pair; the existing constructor is kept.
+    pub fn register<F>(
+        &mut self,
+        api_version: APIVersion,
+        kind: Kind,
+        constructor: F,
+    ) -> Result<(), Error>
+    where
+        F: Fn(&str) -> Result<Box<dyn AssuranceProcedure>, Error> + Send + Sync + 'static,
+    {
+        if self.contains(&api_version, &kind) {
+            return Err(Error::for_system(
+                error::Kind::Conflict,
+                format!(
+                    "A constructor is already registered for api version '{}' and kind '{}'.",
+                    api_version.as_string(),
+                    kind
+                ),
+            ));
+        }
+        self.constructors
+            .insert((api_version, kind), Box::new(constructor));
+        Ok(())
+    }
+
+    /// Returns `true` when a constructor is registered for `api_version` and `kind`.
+    pub fn contains(&self, api_version: &APIVersion, kind: &Kind) -> bool {
+        self.constructors
+            .contains_key(&(api_version.clone(), kind.clone()))
+    }
+
+    /// Builds the procedure in `raw` with the constructor registered for `api_version` and `kind`.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::NotFound`](crate::error::Kind::NotFound) for
+    /// [`Audience::User`](crate::error::Audience::User) is returned when no constructor is
+    /// registered for the pair, since the document declared a version or kind this service does
+    /// not handle. Errors from the constructor are returned unchanged.
+    pub fn build(
+        &self,
+        api_version: &APIVersion,
+        kind: &Kind,
+        raw: &str,
+    ) -> Result<Box<dyn AssuranceProcedure>, Error> {
+        match self.constructors.get(&(api_version.clone(), kind.clone())) {
+            Some(constructor) => constructor(raw),
+            None => Err(Error::for_user(
+                error::Kind::NotFound,
+                format!(
+                    "No specification handler is registered for api version '{}' and kind '{}'.",
+                    api_version.as_string(),
+                    kind
+                ),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
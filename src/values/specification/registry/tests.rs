@@ -0,0 +1,114 @@
+//! Tests for the specification registry, covering registration and dispatch.
+//!
+//! Bounded unit under test: `SpecRegistry`.
+//! Public interfaces verified: `new`, `register`, `contains`, and `build`.
+//! Logical paths covered: dispatch to the constructor registered for a version and kind, passing
+//! the raw text through, constructor errors returned unchanged, the not-found path for an
+//! unregistered version or kind, and duplicate registration rejection.
+//! Requirement validation points: plugin-style selection of specification models.
+
+use super::SpecRegistry;
+use crate::error::{self, Audience, Error};
+use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::kind::Kind;
+use crate::values::specification::traits::AssuranceProcedure;
+use std::any::Any;
+use test_framework_oss::{is_error, is_ok, kernel_error_eq};
+
+#[derive(Debug, PartialEq)]
+struct DummyProcedure {
+    raw: String,
+}
+
+impl AssuranceProcedure for DummyProcedure {
+    fn api_version(&self) -> APIVersion {
+        APIVersion::new(1, 0, 0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn dummy_registry() -> SpecRegistry {
+    let mut registry = SpecRegistry::new();
+    is_ok!(
+        registry.register(APIVersion::new(1, 0, 0), Kind::AssuranceProcedure, |raw| {
+            if raw.is_empty() {
+                return Err(Error::for_user(error::Kind::InvalidInput, "empty document"));
+            }
+            Ok(Box::new(DummyProcedure {
+                raw: raw.to_string(),
+            }) as Box<dyn AssuranceProcedure>)
+        })
+    );
+    registry
+}
+
+#[test]
+/// Requirement validation: verifies a registered version and kind dispatch to their constructor with the raw text.
+fn build_dispatch_success() {
+    let registry = dummy_registry();
+    assert!(registry.contains(&APIVersion::new(1, 0, 0), &Kind::AssuranceProcedure));
+
+    let procedure = is_ok!(registry.build(
+        &APIVersion::new(1, 0, 0),
+        &Kind::AssuranceProcedure,
+        "kind: AssuranceProcedure"
+    ));
+
+    assert_eq!(procedure.api_version(), APIVersion::new(1, 0, 0));
+    assert_eq!(
+        procedure.downcast_ref::<DummyProcedure>(),
+        Some(&DummyProcedure {
+            raw: "kind: AssuranceProcedure".to_string()
+        })
+    );
+}
+
+#[test]
+/// Requirement validation: verifies constructor errors are returned unchanged.
+fn build_constructor_error() {
+    let registry = dummy_registry();
+
+    assert_eq!(
+        is_error!(registry.build(&APIVersion::new(1, 0, 0), &Kind::AssuranceProcedure, "")),
+        Error::for_user(error::Kind::InvalidInput, "empty document")
+    );
+}
+
+#[test]
+/// Requirement validation: verifies an unregistered version or kind is reported as not found.
+fn build_not_found_error() {
+    let registry = dummy_registry();
+    assert!(!registry.contains(&APIVersion::new(2, 0, 0), &Kind::AssuranceProcedure));
+
+    kernel_error_eq!(
+        registry.build(&APIVersion::new(2, 0, 0), &Kind::AssuranceProcedure, "raw"),
+        error::Kind::NotFound,
+        Audience::User,
+        "No specification handler is registered for api version '2.0.0' and kind 'AssuranceProcedure'."
+    );
+    kernel_error_eq!(
+        registry.build(&APIVersion::new(1, 0, 0), &Kind::AssuranceReport, "raw"),
+        error::Kind::NotFound,
+        Audience::User,
+        "No specification handler is registered for api version '1.0.0' and kind 'AssuranceReport'."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a second constructor for the same version and kind is rejected and the first is kept.
+fn register_duplicate_error() {
+    let mut registry = dummy_registry();
+
+    kernel_error_eq!(
+        registry.register(APIVersion::new(1, 0, 0), Kind::AssuranceProcedure, |_| {
+            Err(Error::for_system(error::Kind::Unexpected, "replacement"))
+        }),
+        error::Kind::Conflict,
+        Audience::System,
+        "A constructor is already registered for api version '1.0.0' and kind 'AssuranceProcedure'."
+    );
+    is_ok!(registry.build(&APIVersion::new(1, 0, 0), &Kind::AssuranceProcedure, "raw"));
+}
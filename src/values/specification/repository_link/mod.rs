@@ -1,15 +1,22 @@
 //! Repository link value and builder.
 
-use crate::error::{Error, Kind};
-use crate::values::uri::url::URL;
+use crate::error::message::MessageKey;
+use crate::error::{Audience, Error, Kind};
+use crate::values::uri::url::{URL, from_url};
 use std::fmt;
+use url::Url;
 
 /// The [`RepositoryLink`] value is an NAPE-specific value for capturing the URL for the location of a procedure specification.
 ///
 /// # Assumptions
 ///  * This defaults all schemes to **git://** if a scheme is not provided.
 ///  * This allows url inputs values such as **localhost** to be valid.
+///
+/// With the `serde` feature enabled, a link serializes as its canonical string and deserializes
+/// through [`TryFrom<String>`], which validates against [`RepositoryLink::DEFAULT_ALLOWED_SCHEMES`].
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct RepositoryLink {
     /// Parsed repository URL.
     url: URL,
@@ -21,16 +28,173 @@ impl fmt::Display for RepositoryLink {
     }
 }
 
+impl TryFrom<String> for RepositoryLink {
+    type Error = Error;
+
+    /// Builds a link from a stored string using the default allowed schemes and default scheme.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        RepositoryLink::builder()
+            .allowed_schema(
+                RepositoryLink::DEFAULT_ALLOWED_SCHEMES
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            )
+            .default_scheme(RepositoryLink::DEFAULT_SCHEME)
+            .repo_link(value)
+            .build()
+    }
+}
+
+impl From<RepositoryLink> for String {
+    fn from(link: RepositoryLink) -> Self {
+        link.to_string()
+    }
+}
+
 impl RepositoryLink {
+    /// Schemes accepted when a link is converted from a stored string.
+    pub const DEFAULT_ALLOWED_SCHEMES: [&'static str; 3] = ["file", "git", "https"];
+
+    /// Scheme applied when a stored string has no scheme.
+    pub const DEFAULT_SCHEME: &'static str = "git";
+
+    /// Schemes allowed by [`RepositoryLinkBuilder::git_defaults`].
+    pub const GIT_DEFAULT_SCHEMES: [&'static str; 3] = ["file", "git", "https"];
+
     /// Starts a repository link builder.
     pub fn builder() -> RepositoryLinkBuilder {
         RepositoryLinkBuilder::default()
     }
 
+    /// Validates every entry of a configured repository link list at once.
+    ///
+    /// Returns every link, in input order, when all entries are valid. Otherwise returns one
+    /// error per invalid entry, in input order, each with an `index` context entry giving the
+    /// entry's position in `entries`, so a startup check can report every problem together.
+    pub fn build_all(
+        entries: &[&str],
+        allowed: &[String],
+        default: &str,
+    ) -> Result<Vec<RepositoryLink>, Vec<Error>> {
+        let (links, errors): (Vec<_>, Vec<_>) = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                RepositoryLink::builder()
+                    .allowed_schema(allowed.to_vec())
+                    .default_scheme(default)
+                    .repo_link(*entry)
+                    .build()
+                    .map_err(|error| error.with_context("index", index.to_string()))
+            })
+            .partition(Result::is_ok);
+
+        if errors.is_empty() {
+            Ok(links.into_iter().flatten().collect())
+        } else {
+            Err(errors.into_iter().filter_map(Result::err).collect())
+        }
+    }
+
     /// Returns the parsed URL.
     pub fn url(&self) -> &URL {
         &self.url
     }
+
+    /// Returns the link with its leading `scheme://` removed, for display.
+    ///
+    /// Everything after the scheme separator, including any `user@` userinfo, is kept.
+    pub fn display_without_scheme(&self) -> String {
+        let link = self.to_string();
+        match link.split_once("://") {
+            Some((_, rest)) => rest.to_string(),
+            None => link,
+        }
+    }
+
+    /// Returns the link in a canonical form for comparing and deduplicating repositories.
+    ///
+    /// A single trailing `/` is removed from the path, and a port equal to the scheme's default
+    /// (`80` for `http`, `443` for `https`, `9418` for `git`, and `22` for `ssh`) is removed.
+    pub fn normalized(&self) -> RepositoryLink {
+        let Ok(mut parsed) = Url::parse(&self.to_string()) else {
+            return self.clone();
+        };
+
+        if parsed.port().is_some() && parsed.port() == default_port(parsed.scheme()) {
+            // Clearing a port only fails for links without a host, which never carry a port.
+            let _ = parsed.set_port(None);
+        }
+        if parsed.path().len() > 1 && parsed.path().ends_with('/') {
+            let path = parsed.path()[..parsed.path().len() - 1].to_string();
+            parsed.set_path(&path);
+        }
+
+        RepositoryLink {
+            url: from_url(&parsed),
+        }
+    }
+
+    /// Returns `true` when both links are equal after [`RepositoryLink::normalized`], such as
+    /// `https://github.com/org/repo` and `https://github.com:443/org/repo/`.
+    pub fn normalized_eq(&self, other: &RepositoryLink) -> bool {
+        self.normalized() == other.normalized()
+    }
+
+    /// Returns the first path segment, such as `org` in `https://github.com/org/repo`.
+    ///
+    /// Empty segments from doubled or trailing slashes are skipped. Returns `None` when the path
+    /// has fewer than two segments, since a lone segment does not say which part it is.
+    pub fn owner(&self) -> Option<&str> {
+        let mut segments = self.path_segments();
+        let owner = segments.next()?;
+        segments.next().map(|_| owner)
+    }
+
+    /// Returns the last path segment without a trailing `.git`, such as `repo` in
+    /// `https://github.com/org/repo.git`.
+    ///
+    /// For nested groups, such as `https://gitlab.com/group/sub/repo`, this is the last segment
+    /// while [`RepositoryLink::owner`] is the first. Returns `None` under the same conditions as
+    /// [`RepositoryLink::owner`].
+    pub fn repo_name(&self) -> Option<&str> {
+        let mut segments = self.path_segments();
+        segments.next()?;
+        let last = segments.last()?;
+        Some(last.strip_suffix(".git").unwrap_or(last))
+    }
+
+    /// Returns the non-empty segments of the path, in order.
+    fn path_segments(&self) -> impl Iterator<Item = &str> {
+        self.url
+            .path()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+    }
+
+    /// Returns the first query value for `key`, such as the `main` in `?ref=main`.
+    ///
+    /// Returns `None` when the link has no query parameter named `key`.
+    pub fn query(&self, key: &str) -> Option<&str> {
+        self.url
+            .query_pairs()
+            .iter()
+            .find(|(query_key, _)| query_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns every query value for `key` in the order they appear in the link.
+    ///
+    /// Returns an empty list when the link has no query parameter named `key`.
+    pub fn query_all(&self, key: &str) -> Vec<&str> {
+        self.url
+            .query_pairs()
+            .iter()
+            .filter(|(query_key, _)| query_key == key)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
 }
 
 /// Builds a [`RepositoryLink`].
@@ -45,6 +209,23 @@ pub struct RepositoryLinkBuilder {
 }
 
 impl RepositoryLinkBuilder {
+    /// Starts a builder that allows [`RepositoryLink::GIT_DEFAULT_SCHEMES`] and defaults to
+    /// [`RepositoryLink::DEFAULT_SCHEME`], so only [`RepositoryLinkBuilder::repo_link`] needs to be
+    /// set.
+    ///
+    /// Both settings can still be replaced with [`RepositoryLinkBuilder::allowed_schema`] and
+    /// [`RepositoryLinkBuilder::default_scheme`].
+    pub fn git_defaults() -> Self {
+        RepositoryLinkBuilder::default()
+            .allowed_schema(
+                RepositoryLink::GIT_DEFAULT_SCHEMES
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            )
+            .default_scheme(RepositoryLink::DEFAULT_SCHEME)
+    }
+
     /// Sets the list of allowed URL schemes.
     pub fn allowed_schema(mut self, schema: Vec<String>) -> Self {
         self.allowed_schema = schema;
@@ -69,23 +250,23 @@ impl RepositoryLinkBuilder {
         let default_schema = self.verify_default_scheme(&allowed_schema)?;
         let repo_link = self.verify_repo_link(&allowed_schema, &default_schema)?;
         let url = URL::new(&repo_link).map_err(|error| {
-            Error::for_system(
+            MessageKey::RepositoryLinkMalformed.error(
+                Audience::System,
                 Kind::InvalidInput,
-                format!(
-                    "The provided repository link [{}] is malformed. {}",
-                    &repo_link, error
-                ),
+                &[("repo_link", &repo_link), ("reason", &error.message)],
             )
         })?;
+        verify_host(&url, &repo_link)?;
 
         Ok(RepositoryLink { url })
     }
 
     fn verify_allowed_schema(&self) -> Result<Vec<String>, Error> {
         if self.allowed_schema.is_empty() {
-            return Err(Error::for_system(
+            return Err(MessageKey::RepositoryLinkNoAllowedSchemes.error(
+                Audience::System,
                 Kind::InvalidInput,
-                "No allowed schemes were provided, please provide at least one allowed scheme.",
+                &[],
             ));
         }
         Ok(self.allowed_schema.clone())
@@ -93,28 +274,29 @@ impl RepositoryLinkBuilder {
 
     fn verify_default_scheme(&mut self, allowed_schema: &[String]) -> Result<String, Error> {
         let default_scheme = self.default_scheme.take().ok_or_else(|| {
-            Error::for_user(
+            MessageKey::RepositoryLinkDefaultSchemeMissing.error(
+                Audience::User,
                 Kind::InvalidInput,
-                "A default scheme was not provided. Please provide a default scheme.",
+                &[],
             )
         })?;
 
         if default_scheme.trim().is_empty() {
-            return Err(Error::for_system(
+            return Err(MessageKey::RepositoryLinkDefaultSchemeBlank.error(
+                Audience::System,
                 Kind::InvalidInput,
-                "The provided default scheme is empty or all whitespace. Please provide a non-empty default scheme.",
+                &[],
             ));
         }
 
         if !allowed_schema.contains(&default_scheme) {
-            return Err(Error::for_system(
+            return Err(MessageKey::RepositoryLinkDefaultSchemeNotAllowed.error(
+                Audience::System,
                 Kind::InvalidInput,
-                format!(
-                    "The provided default scheme '{}' is not in the list of allowed schemes: {:?}. \
-                Either provide a default scheme that is in the list of allowed schemes, or update the list of \
-                allowed schema to include the default schema.",
-                    default_scheme, allowed_schema
-                ),
+                &[
+                    ("default_scheme", &default_scheme),
+                    ("allowed_schemes", &format!("{:?}", allowed_schema)),
+                ],
             ));
         }
         Ok(default_scheme)
@@ -126,16 +308,14 @@ impl RepositoryLinkBuilder {
         default_schema: &str,
     ) -> Result<String, Error> {
         let existing_link = self.repo_link.take().ok_or_else(|| {
-            Error::for_system(
-                Kind::InvalidInput,
-                "A repository link was not provided. Please provide a repository link.",
-            )
+            MessageKey::RepositoryLinkMissing.error(Audience::System, Kind::InvalidInput, &[])
         })?;
 
         if existing_link.trim().is_empty() {
-            return Err(Error::for_system(
+            return Err(MessageKey::RepositoryLinkBlank.error(
+                Audience::System,
                 Kind::InvalidInput,
-                "The provided repository link is empty or all whitespace. Please provide a non-empty repository link.",
+                &[],
             ));
         }
 
@@ -166,13 +346,10 @@ impl RepositoryLinkBuilder {
             // Must contain a proper scheme separator '://'
             let parts: Vec<&str> = repo_link.split("://").collect();
             if parts.len() != 2 || parts[1].is_empty() {
-                return Err(Error::for_system(
+                return Err(MessageKey::RepositoryLinkMissingSchemeSeparator.error(
+                    Audience::System,
                     Kind::InvalidInput,
-                    format!(
-                        "The repository link [{}] is malformed. It must either start with a scheme separator '://' \
-            or be formatted as [scheme]://[host] per for the RFC 3986 specification.",
-                        repo_link
-                    ),
+                    &[("repo_link", repo_link)],
                 ));
             }
             Ok(())
@@ -205,13 +382,14 @@ impl RepositoryLinkBuilder {
         if allowed_schemes.contains(&repo_link_schema) {
             Ok(())
         } else {
-            Err(Error::for_system(
+            Err(MessageKey::RepositoryLinkSchemeNotAllowed.error(
+                Audience::System,
                 Kind::InvalidInput,
-                format!(
-                    "The url scheme '{}' is not allowed. Allowed schemes are {:?} \
-                    and the default scheme is '{}'.",
-                    repo_link_schema, allowed_schemes, default_scheme
-                ),
+                &[
+                    ("scheme", &repo_link_schema),
+                    ("allowed_schemes", &format!("{:?}", allowed_schemes)),
+                    ("default_scheme", default_scheme),
+                ],
             ))
         }
     }
@@ -222,5 +400,40 @@ impl RepositoryLinkBuilder {
     }
 }
 
+/// The port a scheme uses when a link does not name one.
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "http" => Some(80),
+        "https" => Some(443),
+        "git" => Some(9418),
+        "ssh" => Some(22),
+        _ => None,
+    }
+}
+
+/// Verifies the parsed host can be cloned from: present unless the link is a local `file://`
+/// link, and free of whitespace or percent-encoded characters.
+fn verify_host(url: &URL, repo_link: &str) -> Result<(), Error> {
+    if url.host.is_empty() {
+        if url.scheme == "file" {
+            return Ok(());
+        }
+        return Err(MessageKey::RepositoryLinkMissingHost.error(
+            Audience::System,
+            Kind::InvalidInput,
+            &[("repo_link", repo_link)],
+        ));
+    }
+
+    if url.host.chars().any(|c| c.is_whitespace() || c == '%') {
+        return Err(MessageKey::RepositoryLinkInvalidHost.error(
+            Audience::System,
+            Kind::InvalidInput,
+            &[("repo_link", repo_link), ("host", &url.host)],
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests;
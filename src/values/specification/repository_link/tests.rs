@@ -1,12 +1,15 @@
 //! Tests for `RepositoryLink`, covering builder defaults and URL validation behavior.
 //!
 //! Bounded unit under test: `RepositoryLink`.
-//! Public interfaces verified: the builder, `to_string`, and URL accessors.
+//! Public interfaces verified: the builder, `to_string`, URL accessors, `query`, `query_all`,
+//! `RepositoryLinkBuilder::git_defaults`, `TryFrom<String>`, `build_all`, `display_without_scheme`, `normalized`, `normalized_eq`, `owner`, `repo_name`, and the feature-gated serde implementations.
 //! Logical paths covered: valid repository links, default scheme application, malformed input,
-//! missing configuration, and scheme validation failures.
+//! missing configuration, scheme validation failures, the git scheme preset, query-parameter lookup, stored-string
+//! round trips, whole-list validation, scheme-free display, host validation including local `file://` links, normalized equality across trailing slashes
+//! and default ports, and owner and repository-name extraction for short, nested, `.git`, and too-short paths.
 //! Requirement validation points: standards-aligned repository-link parsing and normalization.
 
-use super::RepositoryLink;
+use super::{RepositoryLink, RepositoryLinkBuilder};
 use crate::error::{Audience, Kind};
 use std::string::ToString;
 use test_framework_oss::{is_error, is_ok, kernel_error_eq};
@@ -258,3 +261,348 @@ fn unallowed_repo_link_scheme_error() {
         "The url scheme 'ssh' is not allowed. Allowed schemes are [\"file\", \"git\", \"https\"] and the default scheme is 'git'."
     )
 }
+
+#[test]
+/// Requirement validation: verifies a single query value is returned by key.
+fn query_single_value_success() {
+    let repository_link =
+        repository_link_fixture("https://github.com/nape/processes/rust-ci?ref=main");
+
+    assert_eq!(repository_link.query("ref"), Some("main"));
+    assert_eq!(repository_link.query_all("ref"), vec!["main"]);
+}
+
+#[test]
+/// Requirement validation: verifies repeated query keys are all returned in order.
+fn query_repeated_keys_success() {
+    let repository_link =
+        repository_link_fixture("https://github.com/nape/processes/rust-ci?tag=v1&ref=main&tag=v2");
+
+    assert_eq!(repository_link.query("tag"), Some("v1"));
+    assert_eq!(repository_link.query_all("tag"), vec!["v1", "v2"]);
+}
+
+#[test]
+/// Requirement validation: verifies a missing query key returns no values.
+fn query_missing_key_success() {
+    let repository_link =
+        repository_link_fixture("https://github.com/nape/processes/rust-ci?ref=main");
+
+    assert_eq!(repository_link.query("branch"), None);
+    assert!(repository_link.query_all("branch").is_empty());
+}
+
+fn repository_link_fixture(link: &str) -> RepositoryLink {
+    is_ok!(
+        RepositoryLinkBuilder::git_defaults()
+            .repo_link(link)
+            .build()
+    )
+}
+
+#[test]
+/// Requirement validation: verifies a stored string converts using the default schemes.
+fn try_from_string_success() {
+    let result = RepositoryLink::try_from("github.com/nape/processes/rust-ci".to_string());
+
+    let repository_link = is_ok!(result);
+
+    assert_eq!(
+        repository_link.to_string(),
+        "git://github.com/nape/processes/rust-ci"
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a stored string with a disallowed scheme is rejected.
+fn try_from_string_disallowed_scheme_error() {
+    for scheme in ["ftp", "http", "ssh"] {
+        let result =
+            RepositoryLink::try_from(format!("{scheme}://github.com/nape/processes/rust-ci"));
+
+        kernel_error_eq!(
+            &result,
+            Kind::InvalidInput,
+            Audience::System,
+            format!(
+                "The url scheme '{scheme}' is not allowed. Allowed schemes are [\"file\", \"git\", \"https\"] and the default scheme is 'git'."
+            )
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+/// Requirement validation: verifies a link serializes as its canonical string and reads back.
+fn serde_round_trip_success() {
+    let repository_link =
+        repository_link_fixture("https://github.com/nape/processes/rust-ci?ref=main");
+
+    let json = is_ok!(serde_json::to_string(&repository_link));
+    assert_eq!(
+        json,
+        "\"https://github.com/nape/processes/rust-ci?ref=main\""
+    );
+
+    let restored: RepositoryLink = is_ok!(serde_json::from_str(&json));
+    assert_eq!(restored, repository_link);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+/// Requirement validation: verifies a malformed stored string fails deserialization.
+fn serde_malformed_string_error() {
+    let result = serde_json::from_str::<RepositoryLink>("\"https://exa mple.com/repo\"");
+
+    let error = is_error!(result);
+
+    assert!(error.to_string().contains("malformed"));
+}
+
+#[test]
+/// Requirement validation: verifies an all-valid link list builds every link in order.
+fn build_all_success() {
+    let allowed_schema = ["git".to_string(), "https".to_string()].to_vec();
+
+    let result = RepositoryLink::build_all(
+        &["https://github.com/nape/a", "github.com/nape/b"],
+        &allowed_schema,
+        "git",
+    );
+
+    let links = is_ok!(result);
+    let links: Vec<String> = links.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        links,
+        ["https://github.com/nape/a", "git://github.com/nape/b"]
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a mixed link list returns one error per invalid entry.
+fn build_all_mixed_error() {
+    let allowed_schema = ["git".to_string(), "https".to_string()].to_vec();
+
+    let result = RepositoryLink::build_all(
+        &[
+            "ssh://github.com/nape/a",
+            "https://github.com/nape/b",
+            "file://github.com/nape/c",
+        ],
+        &allowed_schema,
+        "git",
+    );
+
+    let errors = is_error!(result);
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|error| error.kind == Kind::InvalidInput));
+    assert!(
+        errors[0]
+            .message
+            .starts_with("The url scheme 'ssh' is not allowed.")
+    );
+    assert_eq!(
+        errors[0].context(),
+        [("index".to_string(), "0".to_string())]
+    );
+    assert!(
+        errors[1]
+            .message
+            .starts_with("The url scheme 'file' is not allowed.")
+    );
+    assert_eq!(
+        errors[1].context(),
+        [("index".to_string(), "2".to_string())]
+    );
+}
+
+#[test]
+/// Requirement validation: verifies the scheme and separator are removed for display.
+fn display_without_scheme_success() {
+    let link = repository_link_fixture("https://github.com/x");
+
+    assert_eq!(link.display_without_scheme(), "github.com/x");
+    assert_eq!(link.to_string(), "https://github.com/x");
+}
+
+#[test]
+/// Requirement validation: verifies userinfo is kept after the scheme is removed.
+fn display_without_scheme_keeps_userinfo_success() {
+    let link = repository_link_fixture("git://git@github.com/nape/processes");
+
+    assert_eq!(
+        link.display_without_scheme(),
+        "git@github.com/nape/processes"
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a link without a host is rejected.
+fn empty_host_error() {
+    let result = RepositoryLink::builder()
+        .allowed_schema(vec!["git".to_string(), "https".to_string()])
+        .default_scheme("git")
+        .repo_link("git:///nape/processes")
+        .build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The repository link [git:///nape/processes] has no host. Please provide a link with a host, such as [scheme]://[host]/[path]."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a host with encoded whitespace is rejected, and a literal
+/// space is still reported as malformed.
+fn host_with_whitespace_error() {
+    let result = RepositoryLink::builder()
+        .allowed_schema(vec!["git".to_string(), "https".to_string()])
+        .default_scheme("git")
+        .repo_link("git://git%20hub.com/nape/processes")
+        .build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The repository link [git://git%20hub.com/nape/processes] has the host 'git%20hub.com', which contains whitespace or percent-encoded characters. Please provide a valid host name."
+    );
+
+    let error = is_error!(
+        RepositoryLink::builder()
+            .allowed_schema(vec!["git".to_string()])
+            .default_scheme("git")
+            .repo_link("git://git hub.com/nape")
+            .build()
+    );
+    assert!(
+        error
+            .message
+            .starts_with("The provided repository link [git://git hub.com/nape] is malformed.")
+    );
+}
+
+#[test]
+/// Requirement validation: verifies hosted links and local `file://` links without a host still
+/// build.
+fn host_validation_valid_links_success() {
+    let hosted = repository_link_fixture("github.com/nape/processes");
+    assert_eq!(hosted.url().host, "github.com");
+
+    let local = repository_link_fixture("file:///srv/git/processes");
+    assert_eq!(local.url().host, "");
+    assert_eq!(local.to_string(), "file:///srv/git/processes");
+}
+
+#[test]
+/// Requirement validation: verifies links differing by a trailing slash are normalized equal.
+fn normalized_trailing_slash_success() {
+    let plain = repository_link_fixture("https://github.com/org/repo");
+    let slashed = repository_link_fixture("https://github.com/org/repo/");
+
+    assert_ne!(plain, slashed);
+    assert!(plain.normalized_eq(&slashed));
+    assert_eq!(
+        slashed.normalized().to_string(),
+        "https://github.com/org/repo"
+    );
+    assert_eq!(slashed.normalized().url().path, "/org/repo");
+    assert!(!plain.normalized_eq(&repository_link_fixture("https://github.com/org/other")));
+}
+
+#[test]
+/// Requirement validation: verifies an explicit default port is removed and other ports are kept.
+fn normalized_default_port_success() {
+    let https = repository_link_fixture("https://github.com:443/org/repo");
+    assert!(https.normalized_eq(&repository_link_fixture("https://github.com/org/repo")));
+
+    let git = repository_link_fixture("git://github.com:9418/org/repo/");
+    assert_eq!(git.normalized().to_string(), "git://github.com/org/repo");
+    assert_eq!(git.normalized().url().port, 0);
+
+    let custom = repository_link_fixture("git://github.com:8080/org/repo");
+    assert_eq!(
+        custom.normalized().to_string(),
+        "git://github.com:8080/org/repo"
+    );
+    assert!(!custom.normalized_eq(&repository_link_fixture("git://github.com/org/repo")));
+}
+
+#[test]
+/// Requirement validation: verifies the git preset accepts an `https` link without further setup.
+fn git_defaults_https_success() {
+    let link = is_ok!(
+        RepositoryLinkBuilder::git_defaults()
+            .repo_link("https://github.com/nape/processes")
+            .build()
+    );
+    assert_eq!(link.to_string(), "https://github.com/nape/processes");
+
+    let link = is_ok!(
+        RepositoryLinkBuilder::git_defaults()
+            .repo_link("github.com/nape/processes")
+            .build()
+    );
+    assert_eq!(link.to_string(), "git://github.com/nape/processes");
+}
+
+#[test]
+/// Requirement validation: verifies the git preset rejects an `ssh` link with the unallowed-scheme
+/// error.
+fn git_defaults_ssh_error() {
+    let result = RepositoryLinkBuilder::git_defaults()
+        .repo_link("ssh://github.com/nape/processes")
+        .build();
+
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The url scheme 'ssh' is not allowed. Allowed schemes are [\"file\", \"git\", \"https\"] and the default scheme is 'git'."
+    );
+}
+
+#[test]
+/// Requirement validation: verifies the owner and repository name of a two-segment path.
+fn owner_and_repo_name_two_segments_success() {
+    let repository_link = repository_link_fixture("https://github.com/org/repo");
+
+    assert_eq!(repository_link.owner(), Some("org"));
+    assert_eq!(repository_link.repo_name(), Some("repo"));
+}
+
+#[test]
+/// Requirement validation: verifies a nested path uses its first and last segments, skipping empty ones.
+fn owner_and_repo_name_deeper_path_success() {
+    let repository_link =
+        repository_link_fixture("https://gitlab.com/group/subgroup/repo/?ref=main");
+
+    assert_eq!(repository_link.owner(), Some("group"));
+    assert_eq!(repository_link.repo_name(), Some("repo"));
+}
+
+#[test]
+/// Requirement validation: verifies a trailing `.git` is stripped from the repository name only.
+fn repo_name_strips_git_suffix_success() {
+    let repository_link = repository_link_fixture("github.com/org.git/repo.git");
+
+    assert_eq!(repository_link.owner(), Some("org.git"));
+    assert_eq!(repository_link.repo_name(), Some("repo"));
+}
+
+#[test]
+/// Requirement validation: verifies paths with fewer than two segments have no owner or repository name.
+fn owner_and_repo_name_too_few_segments_success() {
+    for link in [
+        "https://github.com",
+        "https://github.com/",
+        "https://github.com/org",
+    ] {
+        let repository_link = repository_link_fixture(link);
+
+        assert_eq!(repository_link.owner(), None, "{link}");
+        assert_eq!(repository_link.repo_name(), None, "{link}");
+    }
+}
@@ -1,6 +1,9 @@
+use crate::error::Error;
 use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::assurance_report::diff::{self, ReportDiff};
 use crate::values::specification::kind::Kind;
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Common behavior for assurance procedure value objects.
@@ -15,6 +18,16 @@ pub trait AssuranceProcedure: Any + fmt::Debug {
     fn as_any(&self) -> &dyn Any;
 }
 
+impl dyn AssuranceProcedure {
+    /// Returns the concrete procedure as `T`, or `None` when it is a different type.
+    ///
+    /// This lives on the trait object rather than the trait because a generic trait method would
+    /// stop [`AssuranceProcedure`] from being usable as `dyn AssuranceProcedure`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+}
+
 /// Common behavior for assurance report value objects.
 pub trait AssuranceReport: Any + fmt::Debug {
     /// Returns the API version for this assurance report.
@@ -25,4 +38,33 @@ pub trait AssuranceReport: Any + fmt::Debug {
     }
     /// Returns this value as [`Any`].
     fn as_any(&self) -> &dyn Any;
+    /// Returns the report's comparable fields as name/value pairs.
+    ///
+    /// Used by [`diff`](diff::diff) to compare two runs. The default exists only so report types
+    /// written before this method keep compiling: it reports no fields, which makes **every**
+    /// diff of that type empty. Report types that should be diffable must override it, as
+    /// [`AssuranceReportV1`](crate::values::specification::v1_0_0::assurance_report::AssuranceReportV1)
+    /// does.
+    fn entries(&self) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
+}
+
+impl dyn AssuranceReport {
+    /// Returns the concrete report as `T`, or `None` when it is a different type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+
+    /// Compares this report, as the baseline, against `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`diff::diff`].
+    pub fn diff(&self, other: &dyn AssuranceReport) -> Result<ReportDiff, Error> {
+        diff::diff(self, other)
+    }
 }
+
+#[cfg(test)]
+mod tests;
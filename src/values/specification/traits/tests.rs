@@ -0,0 +1,77 @@
+//! Tests for the specification traits, covering trait-object downcasting.
+//!
+//! Bounded unit under test: the `AssuranceProcedure` and `AssuranceReport` traits.
+//! Public interfaces verified: `downcast_ref` on `dyn AssuranceProcedure` and
+//! `dyn AssuranceReport`, and the default `kind`.
+//! Logical paths covered: downcasting to the implementing type and to an unrelated type.
+//! Requirement validation points: standards-aligned access to concrete specification values.
+
+use super::{AssuranceProcedure, AssuranceReport};
+use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::kind::Kind;
+use std::any::Any;
+
+#[derive(Debug, PartialEq)]
+struct DummyProcedure {
+    label: &'static str,
+}
+
+impl AssuranceProcedure for DummyProcedure {
+    fn api_version(&self) -> APIVersion {
+        APIVersion::new(1, 0, 0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Debug)]
+struct DummyReport;
+
+impl AssuranceReport for DummyReport {
+    fn api_version(&self) -> APIVersion {
+        APIVersion::new(1, 0, 0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[test]
+/// Requirement validation: verifies a procedure trait object downcasts to its concrete type.
+fn procedure_downcast_ref_success() {
+    let procedure: Box<dyn AssuranceProcedure> = Box::new(DummyProcedure { label: "dummy" });
+
+    assert_eq!(procedure.kind(), Kind::AssuranceProcedure);
+    assert_eq!(
+        procedure.downcast_ref::<DummyProcedure>(),
+        Some(&DummyProcedure { label: "dummy" })
+    );
+}
+
+#[test]
+/// Requirement validation: verifies a procedure trait object does not downcast to another type.
+fn procedure_downcast_ref_wrong_type_error() {
+    let procedure: Box<dyn AssuranceProcedure> = Box::new(DummyProcedure { label: "dummy" });
+
+    assert!(procedure.downcast_ref::<DummyReport>().is_none());
+}
+
+#[test]
+/// Requirement validation: verifies a report trait object downcasts to its concrete type.
+fn report_downcast_ref_success() {
+    let report: Box<dyn AssuranceReport> = Box::new(DummyReport);
+
+    assert_eq!(report.kind(), Kind::AssuranceReport);
+    assert!(report.downcast_ref::<DummyReport>().is_some());
+}
+
+#[test]
+/// Requirement validation: verifies a report trait object does not downcast to another type.
+fn report_downcast_ref_wrong_type_error() {
+    let report: Box<dyn AssuranceReport> = Box::new(DummyReport);
+
+    assert!(report.downcast_ref::<DummyProcedure>().is_none());
+}
@@ -7,6 +7,8 @@ use crate::values::specification::assurance_procedure::artifact::Artifact;
 use crate::values::specification::assurance_procedure::artifacts::Artifacts;
 use crate::values::specification::assurance_procedure::procedure::Procedure;
 use crate::values::specification::kind::Kind;
+use crate::values::specification::traits;
+use std::any::Any;
 
 /// Version 1.0.0 assurance procedure value object.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -23,6 +25,16 @@ pub struct AssuranceProcedure {
     pub artifacts: Artifacts,
 }
 
+impl traits::AssuranceProcedure for AssuranceProcedure {
+    fn api_version(&self) -> APIVersion {
+        self.api_version.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 impl AssuranceProcedure {
     /// Returns the API version for this assurance procedure.
     /// Returns the API version.
@@ -30,6 +42,15 @@ impl AssuranceProcedure {
         &self.api_version
     }
 
+    /// Returns `true` when this assurance procedure can be interpreted as the target `version`.
+    ///
+    /// A procedure is compatible with a target version that has the same major version and is
+    /// not older than the procedure's own [`APIVersion`]. Minor and patch releases only add to the
+    /// specification, while a different major version may change its meaning.
+    pub fn is_compatible_with(&self, version: &APIVersion) -> bool {
+        self.api_version.major() == version.major() && &self.api_version <= version
+    }
+
     /// Returns the specification kind.
     /// Returns the specification kind.
     pub fn kind(&self) -> &Kind {
@@ -117,18 +138,35 @@ impl AssuranceProcedureBuilder {
         self
     }
 
+    /// Adds many activities to the builder, each with its actions.
+    pub fn merge_activities(mut self, activities: &[Activity]) -> Self {
+        self.activities.extend(activities.iter().cloned());
+        self
+    }
+
     /// Adds an artifact to the builder.
     pub fn add_artifact(mut self, artifact: &Artifact) -> Self {
         self.artifacts.push(artifact.clone());
         self
     }
 
+    /// Adds every artifact in `artifacts` to the builder.
+    pub fn merge_artifacts(mut self, artifacts: &Artifacts) -> Self {
+        self.artifacts.extend(artifacts.list().iter().cloned());
+        self
+    }
+
     /// Validates the builder and creates an [`AssuranceProcedure`].
+    ///
+    /// The API version, procedure information, and at least one activity are required. Activity
+    /// names must be unique across [`add_activity`](Self::add_activity) and
+    /// [`merge_activities`](Self::merge_activities), and action dependencies must name existing
+    /// actions without forming a cycle.
     pub fn try_build(&self) -> Result<AssuranceProcedure, Error> {
         let api_version = self.build_api_version()?;
         let kind = Kind::AssuranceProcedure;
         let procedure = self.build_procedure()?;
-        let activities = self.build_activities();
+        let activities = self.build_activities()?;
         let artifacts = self.build_artifacts()?;
 
         Ok(AssuranceProcedure {
@@ -176,12 +214,24 @@ impl AssuranceProcedureBuilder {
         })
     }
 
-    fn build_activities(&self) -> Activities {
-        let mut activities = Activities::default();
-        for procedure in &self.activities {
-            activities = activities.merge(procedure)
+    fn build_activities(&self) -> Result<Activities, Error> {
+        if self.activities.is_empty() {
+            return Err(custom_error(
+                "At least one activity is required, but none were provided.",
+            ));
         }
+        let activities = Activities {
+            list: self.activities.clone(),
+        };
         activities
+            .validate_unique_names()
+            .and_then(|()| activities.validate_acyclic())
+            .map_err(|e| {
+                custom_error(
+                    format!("The activities have an issue: {}", e.message.as_str()).as_str(),
+                )
+            })?;
+        Ok(activities)
     }
 
     fn build_artifacts(&self) -> Result<Artifacts, Error> {
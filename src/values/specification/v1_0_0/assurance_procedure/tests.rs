@@ -1,17 +1,22 @@
 //! Tests for the v1.0.0 assurance-procedure wrapper, covering builder success and validation.
 //!
 //! Bounded unit under test: the `v1_0_0::AssuranceProcedure` builder wrapper.
-//! Public interfaces verified: `builder`, `AssuranceProcedureBuilder::default`, and `try_build`.
+//! Public interfaces verified: `builder`, `AssuranceProcedureBuilder::default`, `merge_activities`,
+//! `merge_artifacts`, `try_build`, `is_compatible_with`, and the `AssuranceProcedure` trait.
 //! Logical paths covered: valid construction, missing API version, invalid API version, missing
-//! procedure data, and duplicate artifact rejection.
+//! procedure data, missing activities, duplicate artifact rejection, duplicate activity names,
+//! action dependency cycles and missing dependencies, and API version compatibility.
 //! Requirement validation points: standards-aligned compatibility wrapper behavior for
 //! assurance-procedure construction.
 
 use crate::error;
 use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::assurance_procedure::action::Action;
 use crate::values::specification::assurance_procedure::activity::Activity;
 use crate::values::specification::assurance_procedure::artifact::Artifact;
+use crate::values::specification::assurance_procedure::artifacts::Artifacts;
 use crate::values::specification::kind::Kind;
+use crate::values::specification::traits;
 use crate::values::specification::v1_0_0::assurance_procedure::{
     AssuranceProcedure, AssuranceProcedureBuilder,
 };
@@ -197,3 +202,146 @@ fn builder_error_add_artifact_duplicate_artifact() {
         "The AssuranceProcedure could not be created: The artifact 'artifact-1' has an issue: "
     ));
 }
+
+#[test]
+/// Requirement validation: verifies a procedure is compatible with same-major, newer versions.
+fn is_compatible_with_minor_bump_success() {
+    let procedure = is_ok!(builder().try_build());
+
+    assert!(procedure.is_compatible_with(&APIVersion::new(1, 0, 0)));
+    assert!(procedure.is_compatible_with(&APIVersion::new(1, 1, 0)));
+    assert!(procedure.is_compatible_with(&APIVersion::new(1, 0, 5)));
+}
+
+#[test]
+/// Requirement validation: verifies a procedure is incompatible across a major bump or with an older version.
+fn is_compatible_with_major_bump_error() {
+    let procedure = is_ok!(builder().try_build());
+    let newer_procedure = is_ok!(builder().api_version("1.2.0").try_build());
+
+    assert!(!procedure.is_compatible_with(&APIVersion::new(2, 0, 0)));
+    assert!(!procedure.is_compatible_with(&APIVersion::new(0, 9, 0)));
+    assert!(!newer_procedure.is_compatible_with(&APIVersion::new(1, 1, 0)));
+}
+
+#[test]
+/// Requirement validation: verifies a minimal procedure is usable through the trait.
+fn minimal_procedure_as_trait_success() {
+    let artifacts = is_ok!(Artifacts::default().add(
+        "artifact-1",
+        "Short Desc",
+        &[("key".to_string(), "value".to_string())],
+    ));
+
+    let procedure: Box<dyn traits::AssuranceProcedure> = Box::new(is_ok!(
+        AssuranceProcedure::builder()
+            .api_version("1.0.0")
+            .procedure_info(
+                "nrn:sourcecode::example",
+                "A Short Desc.",
+                "This is an example procedure",
+            )
+            .merge_activities(&[procedure_activity()])
+            .merge_artifacts(&artifacts)
+            .try_build()
+    ));
+
+    assert_eq!(procedure.api_version(), APIVersion::new(1, 0, 0));
+    assert_eq!(procedure.kind(), Kind::AssuranceProcedure);
+    let concrete = procedure.downcast_ref::<AssuranceProcedure>();
+    assert!(concrete.is_some_and(|concrete| concrete.activities().count() == 1));
+    assert!(concrete.is_some_and(|concrete| concrete.artifacts().list().len() == 1));
+}
+
+#[test]
+/// Requirement validation: verifies the builder rejects a procedure without activities.
+fn builder_error_missing_activities() {
+    let result = AssuranceProcedure::builder()
+        .api_version("1.0.0")
+        .procedure_info(
+            "nrn:sourcecode::example",
+            "A Short Desc.",
+            "This is an example procedure",
+        )
+        .try_build();
+
+    let err = is_error!(result);
+    assert_eq!(err.kind, error::Kind::InvalidInput);
+    assert_eq!(err.audience, error::Audience::User);
+    assert_eq!(
+        "The AssuranceProcedure could not be created: At least one activity is required, but none were provided.",
+        err.message
+    );
+}
+
+fn dependent_action(name: &str, depends_on: &str) -> Action {
+    is_ok!(
+        Action::builder()
+            .name(name)
+            .short_description("Short Desc")
+            .long_description("Long Desc")
+            .test_file_path("test_file")
+            .evidence_file_path("evidence")
+            .depends_on(depends_on)
+            .try_build()
+    )
+}
+
+#[test]
+/// Requirement validation: verifies merged activities cannot repeat an activity name.
+fn builder_error_merge_activities_duplicate_name() {
+    let result = builder()
+        .merge_activities(&[procedure_activity()])
+        .try_build();
+
+    let err = is_error!(result);
+    assert_eq!(err.kind, error::Kind::InvalidInput);
+    assert_eq!(err.audience, error::Audience::User);
+    assert_eq!(
+        "The AssuranceProcedure could not be created: The activities have an issue: The activity name 'procedure-1' is used more than once. Activity names must be unique within a procedure.",
+        err.message
+    );
+}
+
+#[test]
+/// Requirement validation: verifies merged activities cannot contain an action dependency cycle
+/// or depend on a missing action.
+fn builder_error_merge_activities_dependency_cycle() {
+    let cyclic = procedure_activity()
+        .append_action(dependent_action("a", "b"))
+        .append_action(dependent_action("b", "a"));
+    let err = is_error!(
+        AssuranceProcedure::builder()
+            .api_version("1.0.0")
+            .procedure_info(
+                "nrn:sourcecode::example",
+                "A Short Desc.",
+                "This is an example procedure",
+            )
+            .merge_activities(&[cyclic])
+            .try_build()
+    );
+    assert_eq!(err.kind, error::Kind::InvalidInput);
+    assert_eq!(err.audience, error::Audience::User);
+    assert_eq!(
+        "The AssuranceProcedure could not be created: The activities have an issue: The action dependencies contain a cycle: a -> b -> a. Please remove one of these dependencies.",
+        err.message
+    );
+
+    let missing = procedure_activity().append_action(dependent_action("a", "missing"));
+    let err = is_error!(
+        AssuranceProcedure::builder()
+            .api_version("1.0.0")
+            .procedure_info(
+                "nrn:sourcecode::example",
+                "A Short Desc.",
+                "This is an example procedure",
+            )
+            .merge_activities(&[missing])
+            .try_build()
+    );
+    assert_eq!(
+        "The AssuranceProcedure could not be created: The activities have an issue: The action 'a' depends on the action 'missing', which does not exist.",
+        err.message
+    );
+}
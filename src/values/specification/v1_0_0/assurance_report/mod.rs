@@ -11,6 +11,7 @@ use crate::values::specification::procedure::Procedure;
 use crate::values::specification::subject::Subject;
 use crate::values::specification::traits::AssuranceReport;
 use std::any::Any;
+use std::collections::BTreeMap;
 
 /// # Overview
 ///
@@ -35,6 +36,51 @@ impl AssuranceReport for AssuranceReportV1 {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    /// Reports the summary counts and outcome, each action's outcome and reason keyed by
+    /// `activities.{activity}.{action}`, and each additional information entry keyed by its text.
+    fn entries(&self) -> BTreeMap<String, String> {
+        let summary = &self.summary;
+        let mut entries = BTreeMap::from([
+            (
+                "summary.activity_count".to_string(),
+                summary.activity_count.to_string(),
+            ),
+            (
+                "summary.action_count".to_string(),
+                summary.action_count.to_string(),
+            ),
+            (
+                "summary.actions_run".to_string(),
+                summary.actions_run.to_string(),
+            ),
+            ("summary.pass".to_string(), summary.pass.to_string()),
+            ("summary.fail".to_string(), summary.fail.to_string()),
+            (
+                "summary.inconclusive".to_string(),
+                summary.inconclusive.to_string(),
+            ),
+            ("summary.outcome".to_string(), summary.outcome.to_string()),
+        ]);
+        for activity in self.activities.list() {
+            for action in activity.actions() {
+                let key = format!(
+                    "activities.{}.{}",
+                    activity.name().value(),
+                    action.name().value()
+                );
+                entries.insert(format!("{key}.outcome"), action.outcome().to_string());
+                entries.insert(format!("{key}.reason"), action.reason().value().to_string());
+            }
+        }
+        for info in self.additional_info.list() {
+            entries.insert(
+                format!("additional_info.{}", info.value()),
+                info.value().to_string(),
+            );
+        }
+        entries
+    }
 }
 
 impl AssuranceReportV1 {
@@ -2,9 +2,11 @@
 //! validation failures.
 //!
 //! Bounded unit under test: the `v1_0_0::AssuranceReport` builder wrapper.
-//! Public interfaces verified: `Builder::new`, `Builder::default`, builder setters, and `try_build`.
+//! Public interfaces verified: `Builder::new`, `Builder::default`, builder setters, `try_build`,
+//! and `AssuranceReport::entries` through `diff`.
 //! Logical paths covered: valid construction, override precedence, missing required inputs,
-//! invalid subject/procedure data, invalid activity data, and invalid additional information.
+//! invalid subject/procedure data, invalid activity data, invalid additional information, and
+//! diffing two reports that are identical or differ in one action outcome.
 //! Requirement validation points: standards-aligned compatibility wrapper behavior for assurance
 //! reports.
 
@@ -16,6 +18,7 @@ use crate::values::specification::assurance_report::action::Action;
 use crate::values::specification::assurance_report::activities::Activities;
 use crate::values::specification::assurance_report::activity::Activity;
 use crate::values::specification::assurance_report::additional_information::AdditionalInformation;
+use crate::values::specification::assurance_report::diff::ChangedEntry;
 use crate::values::specification::assurance_report::signed_file::SignedFile;
 use crate::values::specification::description::Description;
 use crate::values::specification::metadata::MetaData;
@@ -665,3 +668,48 @@ fn builder_additional_information_empty_string_error() {
         "The AssuranceReport could not be created. There is an issue adding your Additional Information to the report. "
     );
 }
+
+fn action_with_outcome(outcome: &Outcome) -> Action {
+    is_ok!(
+        Action::builder()
+            .name("action-name")
+            .use_outcome(outcome)
+            .reason("action reason")
+            .use_test_file_signature(&test_file())
+            .use_evidence_file_signature(&evidence_file())
+            .try_build()
+    )
+}
+
+#[test]
+/// Requirement validation: verifies two identical reports diff as empty and that a changed action
+/// outcome is reported along with the summary counts it changes.
+fn diff_changed_action_outcome_success() {
+    let build = |outcome: &Outcome| {
+        is_ok!(
+            report_base()
+                .add_activity(&activity("activity"))
+                .add_action("activity", &action_with_outcome(outcome))
+                .additional_information("additional information")
+                .try_build()
+        )
+    };
+    let baseline = build(&Outcome::PASS);
+
+    let identical = is_ok!((&baseline as &dyn AssuranceReport).diff(&build(&Outcome::PASS)));
+    assert!(identical.is_empty());
+
+    let changed = is_ok!((&baseline as &dyn AssuranceReport).diff(&build(&Outcome::FAIL)));
+    assert!(changed.added.is_empty());
+    assert!(changed.removed.is_empty());
+    assert!(changed.changed.contains(&ChangedEntry {
+        name: "activities.activity.action-name.outcome".to_string(),
+        before: "pass".to_string(),
+        after: "fail".to_string(),
+    }));
+    assert!(changed.changed.contains(&ChangedEntry {
+        name: "summary.pass".to_string(),
+        before: "1".to_string(),
+        after: "0".to_string(),
+    }));
+}
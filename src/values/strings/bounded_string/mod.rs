@@ -0,0 +1,173 @@
+//! Length-bounded string value and builder.
+
+use crate::error::{Error, Kind};
+use crate::values::Value;
+use crate::values::strings::STRING_256_MAX;
+use std::fmt::{Display, Formatter};
+
+#[cfg(test)]
+mod tests;
+
+/// A string whose length, in characters, is within a minimum and maximum bound.
+///
+/// Use a [`BoundedString`] inside value objects instead of re-implementing length checks.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BoundedString {
+    /// Canonical bounded text.
+    value: String,
+}
+
+impl BoundedString {
+    /// Create a new instance of a [`BoundedStringBuilder`] to create a [`BoundedString`] instance.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::values::strings::bounded_string::BoundedString;
+    ///
+    /// let title = BoundedString::builder()
+    ///     .name("title")
+    ///     .min(1)
+    ///     .max(10)
+    ///     .value("  Weekly  ")
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(title.value(), "Weekly");
+    /// ```
+    pub fn builder() -> BoundedStringBuilder {
+        BoundedStringBuilder::default()
+    }
+
+    /// Retrieve the bounded text.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Value for BoundedString {
+    type ValueType = str;
+
+    fn value(&self) -> &Self::ValueType {
+        self.value.as_str()
+    }
+}
+
+impl Display for BoundedString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+/// Use to build a valid instance of a [`BoundedString`].
+///
+/// By default the input is trimmed, the minimum length is `0`, the maximum length is
+/// [`STRING_256_MAX`], and errors refer to the input as `value`.
+#[derive(Clone, Debug)]
+pub struct BoundedStringBuilder {
+    value: Option<String>,
+    name: String,
+    min: usize,
+    max: usize,
+    trim: bool,
+}
+
+impl Default for BoundedStringBuilder {
+    fn default() -> Self {
+        Self {
+            value: None,
+            name: "value".to_string(),
+            min: 0,
+            max: STRING_256_MAX,
+            trim: true,
+        }
+    }
+}
+
+impl BoundedStringBuilder {
+    /// Provide the text to bound.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Provide the name used to refer to the text in error messages.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Provide the minimum number of characters, inclusive.
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Provide the maximum number of characters, inclusive.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Choose whether leading and trailing whitespace is removed before the length is checked.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Verify and build the [`BoundedString`] instance from the provided builder inputs.
+    ///
+    /// # Errors
+    ///
+    /// - [`Kind::InvalidInput`] for [`Audience::System`](crate::error::Audience::System) if the
+    ///   minimum is greater than the maximum.
+    /// - [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User) if the text
+    ///   is missing or empty while the minimum is greater than `0`.
+    /// - [`Kind::BelowMin`] for [`Audience::User`](crate::error::Audience::User) if the text has
+    ///   fewer characters than the minimum.
+    /// - [`Kind::ExceedsMax`] for [`Audience::User`](crate::error::Audience::User) if the text has
+    ///   more characters than the maximum.
+    pub fn try_build(self) -> Result<BoundedString, Error> {
+        if self.min > self.max {
+            return Err(Error::for_system(
+                Kind::InvalidInput,
+                format!(
+                    "The minimum length {} for the {} is greater than the maximum length {}.",
+                    self.min, self.name, self.max
+                ),
+            ));
+        }
+
+        let raw = self.value.unwrap_or_default();
+        let value = if self.trim { raw.trim() } else { raw.as_str() };
+        let length = value.chars().count();
+
+        if length == 0 && self.min > 0 {
+            return Err(Error::for_user(
+                Kind::InvalidInput,
+                format!("The {} is required, but was empty.", self.name),
+            ));
+        }
+        if length < self.min {
+            return Err(Error::for_user(
+                Kind::BelowMin,
+                format!(
+                    "The {} must have at least {} characters, but has {}.",
+                    self.name, self.min, length
+                ),
+            ));
+        }
+        if length > self.max {
+            return Err(Error::for_user(
+                Kind::ExceedsMax,
+                format!(
+                    "The {} must have at most {} characters, but has {}.",
+                    self.name, self.max, length
+                ),
+            ));
+        }
+
+        Ok(BoundedString {
+            value: value.to_string(),
+        })
+    }
+}
@@ -0,0 +1,141 @@
+//! Verifies the length-bounded string value object.
+//!
+//! Bounded unit under test:
+//! - `BoundedString`
+//!
+//! Public interfaces verified:
+//! - `BoundedString::builder().try_build()`
+//! - `BoundedString::value`
+//! - `Display`
+//!
+//! Logical paths covered:
+//! - values at the minimum and maximum boundaries are accepted
+//! - values one character outside either boundary are rejected
+//! - input is trimmed by default and kept as-is when trimming is disabled
+//! - empty and whitespace-only input is rejected when a minimum is set
+//! - a minimum greater than the maximum is rejected
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::BoundedString;
+use crate::error::{Audience, Kind};
+use test_framework_oss::{is_ok, kernel_error_eq};
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a value at the minimum length is accepted and one shorter is rejected.
+#[test]
+fn min_boundary_success() {
+    let at_min = is_ok!(
+        BoundedString::builder()
+            .min(3)
+            .max(5)
+            .value("abc")
+            .try_build()
+    );
+    assert_eq!(at_min.value(), "abc");
+
+    let below_min = BoundedString::builder()
+        .name("code")
+        .min(3)
+        .max(5)
+        .value("ab")
+        .try_build();
+    kernel_error_eq!(
+        below_min,
+        Kind::BelowMin,
+        Audience::User,
+        "The code must have at least 3 characters, but has 2."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a value at the maximum length is accepted and one longer is rejected.
+#[test]
+fn max_boundary_success() {
+    let at_max = is_ok!(
+        BoundedString::builder()
+            .max(5)
+            .value("  héllo ")
+            .try_build()
+    );
+    assert_eq!(at_max.value(), "héllo");
+    assert_eq!(at_max.to_string(), "héllo");
+
+    let above_max = BoundedString::builder()
+        .name("code")
+        .max(5)
+        .value("abcdef")
+        .try_build();
+    kernel_error_eq!(
+        above_max,
+        Kind::ExceedsMax,
+        Audience::User,
+        "The code must have at most 5 characters, but has 6."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that empty and whitespace-only input is rejected when a minimum is set.
+#[test]
+fn empty_and_whitespace_error() {
+    let whitespace = BoundedString::builder()
+        .name("title")
+        .min(1)
+        .value("   ")
+        .try_build();
+    kernel_error_eq!(
+        whitespace,
+        Kind::InvalidInput,
+        Audience::User,
+        "The title is required, but was empty."
+    );
+
+    let missing = BoundedString::builder().min(1).try_build();
+    kernel_error_eq!(
+        missing,
+        Kind::InvalidInput,
+        Audience::User,
+        "The value is required, but was empty."
+    );
+
+    let optional = is_ok!(BoundedString::builder().value("   ").try_build());
+    assert_eq!(optional.value(), "");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that disabling trimming keeps surrounding whitespace in the length.
+#[test]
+fn trim_disabled_success() {
+    let untrimmed = is_ok!(
+        BoundedString::builder()
+            .trim(false)
+            .min(3)
+            .value(" a ")
+            .try_build()
+    );
+    assert_eq!(untrimmed.value(), " a ");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a minimum greater than the maximum is rejected.
+#[test]
+fn min_greater_than_max_error() {
+    let result = BoundedString::builder()
+        .name("code")
+        .min(6)
+        .max(5)
+        .value("abcdef")
+        .try_build();
+    kernel_error_eq!(
+        result,
+        Kind::InvalidInput,
+        Audience::System,
+        "The minimum length 6 for the code is greater than the maximum length 5."
+    );
+}
@@ -21,5 +21,8 @@ pub fn has_more_than_alphanumeric(input: &str) -> bool {
     !is_only_alphanumeric(input)
 }
 
+/// Length-bounded string value.
+pub mod bounded_string;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,98 @@
+//! Non-empty multi-line text value with normalized line endings.
+
+use crate::error::{Error, Kind};
+use crate::values::Value;
+
+/// Non-empty multi-line text with `\n` line endings and no trailing whitespace on any line.
+///
+/// Unlike a [`Block`](crate::values::text::block::Block), a [`Text`] is validated when it is
+/// built, so it suits required descriptions such as those in assurance reports.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Text {
+    /// Canonical normalized text.
+    value: String,
+}
+
+impl Text {
+    /// Create a new instance of a [`TextBuilder`] to create a [`Text`] instance.
+    ///
+    /// # Example
+    /// ```
+    /// use kernel_oss::values::text::multiline::Text;
+    ///
+    /// let text = Text::builder()
+    ///     .value("First line  \r\nSecond line\rThird line\n")
+    ///     .try_build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(text.value(), "First line\nSecond line\nThird line");
+    /// assert_eq!(text.lines().count(), 3);
+    /// ```
+    pub fn builder() -> TextBuilder {
+        TextBuilder::default()
+    }
+
+    /// Retrieve the normalized text.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Iterate over the lines of the text, without line endings.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.value.split('\n')
+    }
+}
+
+impl Value for Text {
+    type ValueType = str;
+
+    fn value(&self) -> &Self::ValueType {
+        self.value.as_str()
+    }
+}
+
+/// Use to build a valid instance of a [`Text`].
+#[derive(Clone, Debug, Default)]
+pub struct TextBuilder {
+    /// Raw text input.
+    value: Option<String>,
+}
+
+impl TextBuilder {
+    /// Provide the text value.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Normalize and build the [`Text`] instance from the provided builder inputs.
+    ///
+    /// `\r\n` and `\r` line endings become `\n`, trailing whitespace is removed from every line,
+    /// and blank lines before the first and after the last non-blank line are dropped. Leading
+    /// whitespace on a line is kept.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User)
+    /// is returned when the text is missing or contains only whitespace.
+    pub fn try_build(self) -> Result<Text, Error> {
+        let raw = self.value.unwrap_or_default();
+        let normalized = raw.replace("\r\n", "\n").replace('\r', "\n");
+        let lines: Vec<&str> = normalized.split('\n').map(str::trim_end).collect();
+
+        let first = lines.iter().position(|line| !line.is_empty());
+        let last = lines.iter().rposition(|line| !line.is_empty());
+        match (first, last) {
+            (Some(first), Some(last)) => Ok(Text {
+                value: lines[first..=last].join("\n"),
+            }),
+            _ => Err(Error::for_user(
+                Kind::InvalidInput,
+                "The text is required, but only whitespace was provided.".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,56 @@
+//! Tests for `Text`, covering line-ending normalization, trimming, and empty-input rejection.
+//!
+//! Bounded unit under test: `Text`.
+//! Public interfaces verified: `builder`, `TextBuilder::try_build`, `value`, and `lines`.
+//! Logical paths covered: mixed line endings, trailing-whitespace trimming, surrounding blank
+//! lines, preserved indentation, and rejection of empty or whitespace-only input.
+//! Requirement validation points: standards-aligned multi-line text behavior.
+
+use super::Text;
+use crate::error::{Audience, Kind};
+use test_framework_oss::{is_ok, kernel_error_eq};
+
+#[test]
+/// Requirement validation: verifies `\r\n` and `\r` line endings are normalized to `\n`.
+fn mixed_line_endings_success() {
+    let text = is_ok!(Text::builder().value("one\r\ntwo\rthree\nfour").try_build());
+
+    assert_eq!(text.value(), "one\ntwo\nthree\nfour");
+    assert_eq!(
+        text.lines().collect::<Vec<_>>(),
+        vec!["one", "two", "three", "four"]
+    );
+}
+
+#[test]
+/// Requirement validation: verifies trailing whitespace is trimmed per line and indentation is kept.
+fn trailing_space_trimming_success() {
+    let text = is_ok!(
+        Text::builder()
+            .value("\n  \nfirst \t\n  indented  \n\nlast   \r\n \n")
+            .try_build()
+    );
+
+    assert_eq!(text.value(), "first\n  indented\n\nlast");
+    assert_eq!(text.lines().count(), 4);
+}
+
+#[test]
+/// Requirement validation: verifies empty and whitespace-only input is rejected.
+fn empty_after_trim_error() {
+    let whitespace = Text::builder().value(" \r\n\t\r \n").try_build();
+    kernel_error_eq!(
+        whitespace,
+        Kind::InvalidInput,
+        Audience::User,
+        "The text is required, but only whitespace was provided."
+    );
+
+    let missing = Text::builder().try_build();
+    kernel_error_eq!(
+        missing,
+        Kind::InvalidInput,
+        Audience::User,
+        "The text is required, but only whitespace was provided."
+    );
+}
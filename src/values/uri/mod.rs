@@ -2,3 +2,125 @@
 
 /// URL bounded values.
 pub mod url;
+
+#[cfg(test)]
+mod tests;
+
+use crate::error::{Error, Kind};
+
+/// Percent-encodes a single URI component following RFC 3986.
+///
+/// Unreserved characters (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_`, `~`) are kept as-is. Every other
+/// byte of the UTF-8 input, including `/`, `?`, `&`, `=`, and `%`, is written as an uppercase
+/// `%XX` escape, so the result is safe to place in a path segment or query value.
+///
+/// # Example
+/// ```
+/// use kernel_oss::values::uri::encode_component;
+///
+/// assert_eq!(encode_component("a b/ü"), "a%20b%2F%C3%BC");
+/// ```
+pub fn encode_component(component: &str) -> String {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    let mut encoded = String::with_capacity(component.len());
+    for byte in component.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push('%');
+            encoded.push(HEX[(byte >> 4) as usize] as char);
+            encoded.push(HEX[(byte & 0x0F) as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decodes the `%XX` escapes in a URI component following RFC 3986.
+///
+/// Characters that are not part of an escape are kept as-is, and `+` is not treated as a space.
+/// An invalid escape, such as a `%` that is not followed by two hexadecimal digits, is left
+/// literal rather than rejected. Decoded bytes that do not form valid UTF-8 are replaced with
+/// `U+FFFD`.
+///
+/// # Example
+/// ```
+/// use kernel_oss::values::uri::decode_component;
+///
+/// assert_eq!(decode_component("a%20b%2F%C3%BC"), "a b/ü");
+/// assert_eq!(decode_component("100%"), "100%");
+/// ```
+pub fn decode_component(component: &str) -> String {
+    decode_escapes(component).0
+}
+
+/// Decodes the `%XX` escapes in a URI component like [`decode_component`], but rejects an
+/// invalid escape instead of leaving it literal.
+///
+/// Use this for values that must arrive intact, such as identifiers, where a stray `%` means the
+/// component was truncated or mangled in transit.
+///
+/// # Errors
+///
+/// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`](crate::error::Audience::User)
+/// naming the byte offset of the first `%` that is not followed by two hexadecimal digits.
+///
+/// # Example
+/// ```
+/// use kernel_oss::values::uri::decode_component_strict;
+///
+/// assert_eq!(decode_component_strict("a%20b").unwrap(), "a b");
+/// assert!(decode_component_strict("100%").is_err());
+/// ```
+pub fn decode_component_strict(component: &str) -> Result<String, Error> {
+    match decode_escapes(component) {
+        (decoded, None) => Ok(decoded),
+        (_, Some(index)) => Err(Error::for_user(
+            Kind::InvalidInput,
+            format!(
+                "The value '{}' has a malformed percent-encoded sequence at byte {}.",
+                component, index
+            ),
+        )),
+    }
+}
+
+/// Decodes `component`, leaving invalid escapes literal, and returns the byte offset of the
+/// first invalid escape, if any.
+fn decode_escapes(component: &str) -> (String, Option<usize>) {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut malformed = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = match bytes[index] {
+            b'%' => bytes
+                .get(index + 1..index + 3)
+                .and_then(|hex| Some((hex_value(hex[0])? << 4) | hex_value(hex[1])?)),
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                if bytes[index] == b'%' && malformed.is_none() {
+                    malformed = Some(index);
+                }
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    (String::from_utf8_lossy(&decoded).into_owned(), malformed)
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
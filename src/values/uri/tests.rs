@@ -0,0 +1,86 @@
+//! Verifies the URI component encoding helpers.
+//!
+//! Bounded unit under test:
+//! - URI component percent-encoding
+//!
+//! Public interfaces verified:
+//! - `encode_component`
+//! - `decode_component`
+//! - `decode_component_strict`
+//!
+//! Logical paths covered:
+//! - unreserved characters are left unchanged
+//! - spaces and reserved characters are escaped and decoded
+//! - multibyte UTF-8 characters round-trip through multiple `%XX` escapes
+//! - invalid `%` escapes are left literal when decoding
+//! - strict decoding matches lenient decoding for valid escapes and rejects the first invalid one
+//!
+//! Requirement validation points:
+//! - No requirement validation points are currently supplied.
+
+use super::{decode_component, decode_component_strict, encode_component};
+use crate::error::{Audience, Kind};
+use test_framework_oss::{is_ok, kernel_error_eq};
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that unreserved characters pass through both helpers unchanged.
+#[test]
+fn unreserved_characters_success() {
+    let unreserved = "AZaz09-._~";
+    assert_eq!(encode_component(unreserved), unreserved);
+    assert_eq!(decode_component(unreserved), unreserved);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that spaces and reserved characters are escaped and decoded.
+#[test]
+fn spaces_and_reserved_characters_success() {
+    let encoded = encode_component("my file/v1?x=1&y=2%");
+    assert_eq!(encoded, "my%20file%2Fv1%3Fx%3D1%26y%3D2%25");
+    assert_eq!(decode_component(&encoded), "my file/v1?x=1&y=2%");
+    assert_eq!(decode_component("a+b%2fc"), "a+b/c");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that multibyte UTF-8 characters round-trip through multiple escapes.
+#[test]
+fn multibyte_utf8_success() {
+    let encoded = encode_component("héllo 世界");
+    assert_eq!(encoded, "h%C3%A9llo%20%E4%B8%96%E7%95%8C");
+    assert_eq!(decode_component(&encoded), "héllo 世界");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that invalid escapes are left literal instead of being rejected.
+#[test]
+fn invalid_escape_left_literal_success() {
+    assert_eq!(decode_component("100%"), "100%");
+    assert_eq!(decode_component("%4"), "%4");
+    assert_eq!(decode_component("%zz%20"), "%zz ");
+    assert_eq!(decode_component("%FF"), "\u{FFFD}");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that strict decoding matches lenient decoding for valid escapes and reports the
+/// offset of the first invalid escape.
+#[test]
+fn strict_decode_invalid_escape_error() {
+    assert_eq!(is_ok!(decode_component_strict("a%20b%2F%C3%BC")), "a b/ü");
+    kernel_error_eq!(
+        decode_component_strict("a%2Fb%zz%"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value 'a%2Fb%zz%' has a malformed percent-encoded sequence at byte 5."
+    );
+    kernel_error_eq!(
+        decode_component_strict("100%"),
+        Kind::InvalidInput,
+        Audience::User,
+        "The value '100%' has a malformed percent-encoded sequence at byte 3."
+    );
+}
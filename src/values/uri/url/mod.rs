@@ -1,6 +1,7 @@
 use crate::error::{Error, Kind};
 use crate::values::text::line::Line;
-use url::{Host, Url};
+use crate::values::uri::decode_component;
+use url::{Host, Url, form_urlencoded};
 
 /// A parsed URL value with exposed components.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -17,7 +18,7 @@ pub struct URL {
     pub path: String,
     /// The raw URL query string.
     pub query_string: String,
-    /// The parsed query pairs.
+    /// The parsed query pairs, with keys and values percent-decoded.
     pub queries: Vec<(String, String)>,
     /// The URL fragment.
     pub fragment: String,
@@ -28,28 +29,29 @@ impl URL {
     pub fn new(url: &str) -> Result<Self, Error> {
         let raw_input = Line::new(url);
         Url::parse(&raw_input.value())
-            .map_err(|error| {
-                Error::for_user(
-                    Kind::InvalidInput,
-                    format!(
-                        "You provided an invalid Url. \
-                            Your Input: [{0}], The Issue: [{1}]",
-                        raw_input.value(),
-                        error
-                    ),
-                )
-            })
-            .map(|parsed_url| URL {
-                value: raw_input,
-                scheme: parsed_url.scheme().to_string(),
-                host: parsed_url.host().unwrap_or(Host::Domain("")).to_string(),
-                port: parsed_url.port().unwrap_or(0),
-                path: parsed_url.path().to_string(),
-                query_string: parsed_url.query().unwrap_or("").to_string(),
-                queries: extract_multiple_queries(&parsed_url),
-                fragment: parsed_url.fragment().unwrap_or("").to_string(),
-            })
+            .map_err(|error| invalid_url_error(&raw_input.value(), error))
+            .map(|parsed_url| from_parsed(raw_input, &parsed_url))
+    }
+
+    /// Creates a new builder for assembling a [`URL`] from its components.
+    ///
+    /// ```
+    /// use kernel_oss::values::uri::url::URL;
+    ///
+    /// let url = URL::builder()
+    ///     .scheme("https")
+    ///     .host("example.com")
+    ///     .path("/repo")
+    ///     .query("ref", "main")
+    ///     .try_build()
+    ///     .expect("expected a valid url");
+    ///
+    /// assert_eq!(url.value(), "https://example.com/repo?ref=main");
+    /// ```
+    pub fn builder() -> URLBuilder {
+        URLBuilder::default()
     }
+
     /// Returns the original URL text.
     pub fn value(&self) -> String {
         self.value.value()
@@ -74,15 +76,21 @@ impl URL {
     pub fn path(&self) -> &str {
         &self.path
     }
+    /// Returns the URL path with its `%XX` escapes decoded.
+    ///
+    /// Invalid escapes are left literal; see [`decode_component`].
+    pub fn decoded_path(&self) -> String {
+        decode_component(&self.path)
+    }
     /// Returns the raw query string.
     pub fn query_string(&self) -> &str {
         &self.query_string
     }
-    /// Returns the parsed query pairs as owned values.
+    /// Returns the percent-decoded query pairs as owned values.
     pub fn queries(&self) -> Vec<(String, String)> {
         self.queries.clone()
     }
-    /// Returns the parsed query pairs as a slice.
+    /// Returns the percent-decoded query pairs as a slice.
     pub fn query_pairs(&self) -> &[(String, String)] {
         &self.queries
     }
@@ -90,12 +98,180 @@ impl URL {
     pub fn query_count(&self) -> usize {
         self.queries.len()
     }
+    /// Re-encodes `queries` as a `k=v&k2=v2` query string, without a leading `?`.
+    ///
+    /// Keys and values are encoded the same way [`URLBuilder::query`] encodes them, so a space
+    /// becomes `+` and reserved characters such as `&` and `=` are percent-encoded. An empty query
+    /// set produces an empty string.
+    pub fn rebuild_query(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.queries.iter())
+            .finish()
+    }
+    /// Appends a query pair and refreshes `query_string` and the URL text to match.
+    ///
+    /// If the URL text was replaced with text that no longer parses, only `queries` and
+    /// `query_string` are updated.
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        self.queries.push((key.to_string(), value.to_string()));
+        self.query_string = self.rebuild_query();
+        if let Ok(mut parsed_url) = Url::parse(&self.value()) {
+            parsed_url.set_query(Some(&self.query_string));
+            self.value = Line::new(parsed_url.as_str());
+        }
+        self
+    }
     /// Returns the URL fragment.
     pub fn fragment(&self) -> &str {
         &self.fragment
     }
 }
 
+/// Builder for [`URL`].
+///
+/// The assembled URL is normalized, so a port matching the scheme's default is
+/// omitted and query keys and values are percent-encoded.
+#[derive(Clone, Debug, Default)]
+pub struct URLBuilder {
+    scheme: Option<String>,
+    host: Option<String>,
+    port: Option<u32>,
+    path: Option<String>,
+    queries: Vec<(String, String)>,
+    fragment: Option<String>,
+}
+
+impl URLBuilder {
+    /// Sets the URL scheme, such as `https`.
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = Some(scheme.to_string());
+        self
+    }
+
+    /// Sets the URL host.
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// Sets the URL port. The port must fit in a `u16`.
+    pub fn port(mut self, port: u32) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the URL path.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    /// Appends a query pair. Repeated keys are kept in insertion order.
+    pub fn query(mut self, key: &str, value: &str) -> Self {
+        self.queries.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the URL fragment.
+    pub fn fragment(mut self, fragment: &str) -> Self {
+        self.fragment = Some(fragment.to_string());
+        self
+    }
+
+    /// Validates the builder and creates a [`URL`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] with [`Kind::InvalidInput`] when the scheme or host
+    /// is missing, the port does not fit in a `u16`, or the assembled URL
+    /// cannot be parsed.
+    pub fn try_build(self) -> Result<URL, Error> {
+        let scheme = required_component("scheme", &self.scheme)?;
+        let host = required_component("host", &self.host)?;
+        let port = self.validate_port()?;
+
+        let authority = format!("{scheme}://{host}");
+        let mut parsed_url =
+            Url::parse(&authority).map_err(|error| invalid_url_error(&authority, error))?;
+
+        if let Some(port) = port {
+            parsed_url.set_port(Some(port)).map_err(|_| {
+                Error::for_user(
+                    Kind::InvalidInput,
+                    format!("The URL scheme [{scheme}] does not support a port."),
+                )
+            })?;
+        }
+        if let Some(path) = &self.path {
+            parsed_url.set_path(path);
+        }
+        if !self.queries.is_empty() {
+            parsed_url
+                .query_pairs_mut()
+                .extend_pairs(self.queries.iter());
+        }
+        if let Some(fragment) = &self.fragment {
+            parsed_url.set_fragment(Some(fragment));
+        }
+
+        Ok(from_parsed(Line::new(parsed_url.as_str()), &parsed_url))
+    }
+
+    fn validate_port(&self) -> Result<Option<u16>, Error> {
+        match self.port {
+            Some(port) => u16::try_from(port).map(Some).map_err(|_| {
+                Error::for_user(
+                    Kind::InvalidInput,
+                    format!(
+                        "The URL port [{port}] is out of range. A port must be between 0 and {}.",
+                        u16::MAX
+                    ),
+                )
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+fn required_component<'a>(name: &str, component: &'a Option<String>) -> Result<&'a str, Error> {
+    match component.as_deref().map(str::trim) {
+        Some(value) if !value.is_empty() => Ok(value),
+        _ => Err(Error::for_user(
+            Kind::InvalidInput,
+            format!("Please provide a {name} for the URL."),
+        )),
+    }
+}
+
+fn invalid_url_error(input: &str, error: url::ParseError) -> Error {
+    Error::for_user(
+        Kind::InvalidInput,
+        format!(
+            "You provided an invalid Url. \
+                Your Input: [{0}], The Issue: [{1}]",
+            input, error
+        ),
+    )
+}
+
+/// Creates a [`URL`] whose text is the serialized form of an already parsed `parsed_url`.
+pub(crate) fn from_url(parsed_url: &Url) -> URL {
+    from_parsed(Line::new(parsed_url.as_str()), parsed_url)
+}
+
+fn from_parsed(value: Line, parsed_url: &Url) -> URL {
+    URL {
+        value,
+        scheme: parsed_url.scheme().to_string(),
+        host: parsed_url.host().unwrap_or(Host::Domain("")).to_string(),
+        port: parsed_url.port().unwrap_or(0),
+        path: parsed_url.path().to_string(),
+        query_string: parsed_url.query().unwrap_or("").to_string(),
+        queries: extract_multiple_queries(parsed_url),
+        fragment: parsed_url.fragment().unwrap_or("").to_string(),
+    }
+}
+
 fn extract_multiple_queries(parsed_url: &Url) -> Vec<(String, String)> {
     let mut queries: Vec<(String, String)> = Vec::new();
     for (key, value) in parsed_url.query_pairs() {
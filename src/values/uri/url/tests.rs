@@ -11,23 +11,38 @@
 //! - `URL::host`
 //! - `URL::port`
 //! - `URL::path`
+//! - `URL::decoded_path`
 //! - `URL::query_string`
 //! - `URL::queries`
 //! - `URL::query_pairs`
 //! - `URL::query_count`
+//! - `URL::rebuild_query`
+//! - `URL::with_query`
 //! - `URL::fragment`
+//! - `URL::builder`
+//! - `URLBuilder::try_build`
 //!
 //! Logical paths covered:
 //! - valid URL parsing succeeds with scheme, host, port, path, query, and fragment
 //! - URLs with only scheme and host default the remaining fields
 //! - multiple query parameters are preserved
 //! - malformed URL input is rejected
+//! - percent-encoded paths and query values are decoded
+//! - invalid percent escapes in the path are left literal
+//! - rebuilding the query after adding a parameter updates the query string and URL text
+//! - rebuilt queries percent-encode special characters and round-trip through parsing
+//! - an empty query set rebuilds as an empty string
+//! - the builder assembles a normalized URL from its components
+//! - the builder omits a port matching the scheme default
+//! - the builder keeps repeated query keys in order
+//! - the builder rejects a missing scheme or host and an out-of-range port
 //!
 //! Requirement validation points:
 //! - No requirement validation points are currently supplied.
 
 use super::URL;
 use crate::error::{Audience, Kind};
+use test_framework_oss::{is_ok, kernel_error_eq};
 
 /// Requirement validation: No requirement validation point is currently supplied.
 ///
@@ -183,3 +198,206 @@ fn create_from_bad_url_input_error() {
         }
     }
 }
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the builder assembles every component into a normalized URL.
+#[test]
+fn builder_full_url_success() {
+    let url_result = URL::builder()
+        .scheme("http")
+        .host("www.example.com")
+        .port(8080)
+        .path("/some/path")
+        .query("query", "value")
+        .fragment("the-fragment")
+        .try_build();
+    is_ok!(&url_result);
+    let url = url_result.unwrap();
+
+    assert_eq!(
+        url.value(),
+        "http://www.example.com:8080/some/path?query=value#the-fragment"
+    );
+    assert_eq!(url.scheme(), "http");
+    assert_eq!(url.host(), "www.example.com");
+    assert_eq!(url.port(), 8080);
+    assert_eq!(url.path(), "/some/path");
+    assert_eq!(url.query_string(), "query=value");
+    assert_eq!(url.fragment(), "the-fragment");
+    assert_eq!(
+        Ok(url.clone()),
+        URL::new("http://www.example.com:8080/some/path?query=value#the-fragment")
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the builder omits the port when none is set or when it matches
+/// the scheme default.
+#[test]
+fn builder_default_port_omission_success() {
+    let without_port = URL::builder()
+        .scheme("https")
+        .host("example.com")
+        .try_build();
+    is_ok!(&without_port);
+    let without_port = without_port.unwrap();
+    assert_eq!(without_port.value(), "https://example.com/");
+    assert_eq!(without_port.port(), 0);
+
+    let default_port = URL::builder()
+        .scheme("https")
+        .host("example.com")
+        .port(443)
+        .path("repo")
+        .try_build();
+    is_ok!(&default_port);
+    let default_port = default_port.unwrap();
+    assert_eq!(default_port.value(), "https://example.com/repo");
+    assert_eq!(default_port.port(), 0);
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that repeated query keys are kept in insertion order and encoded.
+#[test]
+fn builder_repeated_query_keys_success() {
+    let url_result = URL::builder()
+        .scheme("https")
+        .host("example.com")
+        .query("tag", "one")
+        .query("tag", "two words")
+        .query("ref", "main")
+        .try_build();
+    is_ok!(&url_result);
+    let url = url_result.unwrap();
+
+    assert_eq!(
+        url.value(),
+        "https://example.com/?tag=one&tag=two+words&ref=main"
+    );
+    assert_eq!(
+        url.query_pairs(),
+        &[
+            ("tag".to_string(), "one".to_string()),
+            ("tag".to_string(), "two words".to_string()),
+            ("ref".to_string(), "main".to_string())
+        ]
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the builder rejects a missing or blank scheme and host.
+#[test]
+fn builder_missing_components_error() {
+    let missing_scheme = URL::builder().host("example.com").try_build();
+    kernel_error_eq!(
+        missing_scheme,
+        Kind::InvalidInput,
+        Audience::User,
+        "Please provide a scheme for the URL."
+    );
+
+    let blank_host = URL::builder().scheme("https").host("  ").try_build();
+    kernel_error_eq!(
+        blank_host,
+        Kind::InvalidInput,
+        Audience::User,
+        "Please provide a host for the URL."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that the builder rejects a port that does not fit in a `u16`.
+#[test]
+fn builder_port_out_of_range_error() {
+    let url_result = URL::builder()
+        .scheme("https")
+        .host("example.com")
+        .port(65_536)
+        .try_build();
+    kernel_error_eq!(
+        url_result,
+        Kind::InvalidInput,
+        Audience::User,
+        "The URL port [65536] is out of range. A port must be between 0 and 65535."
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that spaces and multibyte UTF-8 escapes are decoded in the path
+/// and in query values.
+#[test]
+fn decoded_path_and_queries_success() {
+    let url = is_ok!(URL::new(
+        "https://example.com/my%20docs/%C3%A9t%C3%A9.txt?name=Ren%C3%A9e%20M&tag=a%26b"
+    ));
+
+    assert_eq!(url.path(), "/my%20docs/%C3%A9t%C3%A9.txt");
+    assert_eq!(url.decoded_path(), "/my docs/été.txt");
+    assert_eq!(
+        url.query_pairs(),
+        &[
+            ("name".to_string(), "Renée M".to_string()),
+            ("tag".to_string(), "a&b".to_string())
+        ]
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that an invalid percent escape in the path is left literal.
+#[test]
+fn decoded_path_invalid_escape_success() {
+    let url = is_ok!(URL::new("https://example.com/100%/a%2"));
+
+    assert_eq!(url.decoded_path(), "/100%/a%2");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that adding a parameter rebuilds the query string and the URL text, keeping the
+/// fragment in place.
+#[test]
+fn with_query_rebuilds_query_success() {
+    let url = is_ok!(URL::new("https://example.com/repo?ref=main#readme")).with_query("depth", "1");
+
+    assert_eq!(url.rebuild_query(), "ref=main&depth=1");
+    assert_eq!(url.query_string(), "ref=main&depth=1");
+    assert_eq!(url.query_count(), 2);
+    assert_eq!(
+        url.value(),
+        "https://example.com/repo?ref=main&depth=1#readme"
+    );
+    assert_eq!(url.fragment(), "readme");
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that special characters are percent-encoded and decode back to the same pairs.
+#[test]
+fn rebuild_query_encodes_special_characters_success() {
+    let url = is_ok!(URL::new("https://example.com/search")).with_query("q", "a&b=c d/é");
+
+    assert_eq!(url.rebuild_query(), "q=a%26b%3Dc+d%2F%C3%A9");
+    let reparsed = is_ok!(URL::new(&url.value()));
+    assert_eq!(
+        reparsed.query_pairs(),
+        &[("q".to_string(), "a&b=c d/é".to_string())]
+    );
+}
+
+/// Requirement validation: No requirement validation point is currently supplied.
+///
+/// Verifies that a URL without queries rebuilds an empty query string.
+#[test]
+fn rebuild_query_empty_success() {
+    let url = is_ok!(URL::new("https://example.com/repo"));
+
+    assert_eq!(url.rebuild_query(), "");
+    assert_eq!(url.query_string(), "");
+}